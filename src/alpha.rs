@@ -0,0 +1,192 @@
+use imgref::{ImgRef, ImgVec};
+use rgb::RGBA8;
+
+use crate::pattern::{self, LINE_DST_WIDTH};
+
+/// Whether a resizer composites alpha in premultiplied or straight form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaHandling {
+    /// Filters in premultiplied space, so transparent regions don't
+    /// contribute color to partially-covered edge pixels.
+    Premultiplied,
+    /// Filters each channel (including alpha) independently, so a
+    /// transparent region's color leaks into edge pixels.
+    Straight,
+    /// Not enough partially-transparent pixels to tell.
+    Unknown,
+}
+
+impl std::fmt::Display for AlphaHandling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Premultiplied => f.write_str("Premultiplied"),
+            Self::Straight => f.write_str("Straight"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// The resize callback type for alpha-aware analysis: takes an RGBA source
+/// image and target dimensions, returns the resized RGBA image.
+pub type AlphaResizeFn = dyn Fn(ImgRef<'_, RGBA8>, usize, usize) -> ImgVec<RGBA8>;
+
+/// Below this fringe score, the edge color is close enough to neutral to
+/// call it premultiplied; above this, the chroma leak is unmistakable.
+const NEUTRAL_THRESHOLD: f64 = 8.0;
+const LEAK_THRESHOLD: f64 = 25.0;
+
+/// Result of probing a resizer's alpha compositing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlphaFringeResult {
+    pub handling: AlphaHandling,
+    /// Mean chroma distance of partially-transparent edge pixels from the
+    /// expected neutral (opaque foreground) color, weighted by
+    /// `alpha * (1 - alpha)` so fully opaque/transparent pixels (which
+    /// carry no information either way) don't dilute the signal.
+    pub fringe_score: f64,
+}
+
+/// Detect whether a resizer composites in premultiplied or straight alpha.
+///
+/// Resizes [`pattern::generate_alpha_pattern`] (an opaque white column over
+/// a transparent magenta background) and inspects the color of
+/// partially-transparent output pixels near the edge. A straight-alpha
+/// resizer blends the background's magenta into the edge color; a
+/// premultiplied one keeps it neutral white, since the transparent
+/// region contributes nothing once weighted by its own zero alpha. This
+/// mirrors the Src-vs-Over compositing distinction.
+pub fn detect(resize: &AlphaResizeFn) -> AlphaFringeResult {
+    let src = pattern::generate_alpha_pattern();
+    let dst_w = LINE_DST_WIDTH;
+    let dst_h = src.height();
+    let resized = resize(src.as_ref(), dst_w, dst_h);
+
+    if resized.width() != dst_w || resized.height() != dst_h {
+        return AlphaFringeResult {
+            handling: AlphaHandling::Unknown,
+            fringe_score: 0.0,
+        };
+    }
+
+    let scanline = resized.height() / 2;
+    let row = &resized.buf()[scanline * resized.stride()..][..dst_w];
+
+    let mut weighted_distance = 0.0;
+    let mut weight_sum = 0.0;
+    for px in row {
+        if px.a == 0 || px.a == 255 {
+            continue;
+        }
+        let alpha = px.a as f64 / 255.0;
+        let weight = alpha * (1.0 - alpha);
+
+        // Expected neutral color is the opaque foreground's white.
+        let dr = px.r as f64 - 255.0;
+        let dg = px.g as f64 - 255.0;
+        let db = px.b as f64 - 255.0;
+        let distance = (dr * dr + dg * dg + db * db).sqrt();
+
+        weighted_distance += weight * distance;
+        weight_sum += weight;
+    }
+
+    if weight_sum < 1e-9 {
+        return AlphaFringeResult {
+            handling: AlphaHandling::Unknown,
+            fringe_score: 0.0,
+        };
+    }
+
+    let fringe_score = weighted_distance / weight_sum;
+    let handling = if fringe_score < NEUTRAL_THRESHOLD {
+        AlphaHandling::Premultiplied
+    } else if fringe_score > LEAK_THRESHOLD {
+        AlphaHandling::Straight
+    } else {
+        AlphaHandling::Unknown
+    };
+
+    AlphaFringeResult {
+        handling,
+        fringe_score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Linear-interpolates neighboring source pixels along x. When
+    /// `premultiply` is true it filters in premultiplied space and
+    /// un-premultiplies the result (correct compositing); otherwise it
+    /// blends each channel independently, including alpha (the common bug).
+    fn interpolated_resize(
+        src: ImgRef<'_, RGBA8>,
+        dst_w: usize,
+        dst_h: usize,
+        premultiply: bool,
+    ) -> ImgVec<RGBA8> {
+        let src_w = src.width();
+        let mut dst = vec![RGBA8::new(0, 0, 0, 0); dst_w * dst_h];
+
+        for y in 0..dst_h {
+            for x in 0..dst_w {
+                let sx = (x as f64 + 0.5) * src_w as f64 / dst_w as f64 - 0.5;
+                let x0 = sx.floor().clamp(0.0, (src_w - 1) as f64) as usize;
+                let x1 = (x0 + 1).min(src_w - 1);
+                let frac = (sx - x0 as f64).clamp(0.0, 1.0);
+
+                let p0 = src.buf()[y * src.stride() + x0];
+                let p1 = src.buf()[y * src.stride() + x1];
+
+                dst[y * dst_w + x] = if premultiply {
+                    blend_premultiplied(p0, p1, frac)
+                } else {
+                    blend_straight(p0, p1, frac)
+                };
+            }
+        }
+
+        ImgVec::new(dst, dst_w, dst_h)
+    }
+
+    fn blend_straight(p0: RGBA8, p1: RGBA8, frac: f64) -> RGBA8 {
+        let lerp = |c0: u8, c1: u8| -> u8 {
+            (c0 as f64 * (1.0 - frac) + c1 as f64 * frac).round() as u8
+        };
+        RGBA8::new(lerp(p0.r, p1.r), lerp(p0.g, p1.g), lerp(p0.b, p1.b), lerp(p0.a, p1.a))
+    }
+
+    fn blend_premultiplied(p0: RGBA8, p1: RGBA8, frac: f64) -> RGBA8 {
+        let a0 = p0.a as f64 / 255.0;
+        let a1 = p1.a as f64 / 255.0;
+        let lerp_channel = |c0: u8, a0: f64, c1: u8, a1: f64| -> f64 {
+            (c0 as f64 * a0) * (1.0 - frac) + (c1 as f64 * a1) * frac
+        };
+        let a = a0 * (1.0 - frac) + a1 * frac;
+        let (r, g, b) = if a > 1e-6 {
+            (
+                (lerp_channel(p0.r, a0, p1.r, a1) / a).round() as u8,
+                (lerp_channel(p0.g, a0, p1.g, a1) / a).round() as u8,
+                (lerp_channel(p0.b, a0, p1.b, a1) / a).round() as u8,
+            )
+        } else {
+            (0, 0, 0)
+        };
+        RGBA8::new(r, g, b, (a * 255.0).round() as u8)
+    }
+
+    #[test]
+    fn premultiplied_resize_keeps_neutral_edge_color() {
+        let result = detect(&|src, w, h| interpolated_resize(src, w, h, true));
+        assert_eq!(result.handling, AlphaHandling::Premultiplied);
+        assert!(result.fringe_score < NEUTRAL_THRESHOLD);
+    }
+
+    #[test]
+    fn straight_resize_leaks_background_chroma() {
+        let result = detect(&|src, w, h| interpolated_resize(src, w, h, false));
+        assert_eq!(result.handling, AlphaHandling::Straight);
+        assert!(result.fringe_score > LEAK_THRESHOLD);
+    }
+}
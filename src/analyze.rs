@@ -1,5 +1,6 @@
 use imgref::ImgRef;
 
+use crate::colorspace::Transfer;
 use crate::pattern::{
     BRIGHT, DARK, DOT_DST_HEIGHT, DOT_DST_WIDTH, DOT_HCENTER, DOT_HPIXELSPAN, DOT_NUM_STRIPS,
     DOT_SRC_WIDTH, DOT_STRIP_HEIGHT, LINE_DST_HEIGHT, LINE_DST_WIDTH, LINE_SRC_WIDTH,
@@ -19,27 +20,24 @@ pub struct FilterCurve {
     pub is_scatter: bool,
 }
 
-fn srgb_to_linear(v: f64) -> f64 {
-    if v <= 0.04045 {
-        v / 12.92
-    } else {
-        ((v + 0.055) / 1.055).powf(2.4)
-    }
-}
-
-/// Read a pixel value, optionally applying sRGB correction.
-/// Returns a value in the range where DARK=50 and BRIGHT=250.
-fn read_pixel(img: &ImgRef<'_, u8>, x: usize, y: usize, srgb: bool) -> f64 {
-    let raw = img.buf()[y * img.stride() + x] as f64;
-    if srgb {
-        let srgb50_lin = srgb_to_linear(50.0 / 255.0);
-        let srgb250_lin = srgb_to_linear(250.0 / 255.0);
-        let v_lin = srgb_to_linear(raw / 255.0);
-        (v_lin - srgb50_lin) * ((BRIGHT as f64 - DARK as f64) / (srgb250_lin - srgb50_lin))
-            + DARK as f64
-    } else {
-        raw
+/// Read a pixel value, decoding through `transfer` before rescaling back
+/// into the range where DARK=50 and BRIGHT=250. `lut` is `transfer`'s
+/// precomputed decode table, passed in so callers only build it once.
+pub(crate) fn read_pixel(
+    img: &ImgRef<'_, u8>,
+    x: usize,
+    y: usize,
+    transfer: &Transfer,
+    lut: &[f32; 256],
+) -> f64 {
+    let raw = img.buf()[y * img.stride() + x];
+    if *transfer == Transfer::Linear {
+        return raw as f64;
     }
+    let dark_lin = transfer.decode(DARK as f64 / 255.0);
+    let bright_lin = transfer.decode(BRIGHT as f64 / 255.0);
+    let v_lin = lut[raw as usize] as f64;
+    (v_lin - dark_lin) * ((BRIGHT as f64 - DARK as f64) / (bright_lin - dark_lin)) + DARK as f64
 }
 
 /// Reconstruct the filter curve from a resized dot pattern image (downscale analysis).
@@ -47,7 +45,8 @@ fn read_pixel(img: &ImgRef<'_, u8>, x: usize, y: usize, srgb: bool) -> f64 {
 /// The dot pattern has 25 strips, each with bright dots at phase-offset positions.
 /// By analyzing where each output pixel falls relative to the nearest dot,
 /// we reconstruct the filter kernel as a scatter plot.
-pub fn analyze_dot(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
+pub fn analyze_dot(img: &ImgRef<'_, u8>, transfer: Transfer) -> FilterCurve {
+    let lut = transfer.decode_lut();
     let w = img.width();
     let h = img.height();
     let scale_factor = w as f64 / DOT_SRC_WIDTH as f64;
@@ -89,7 +88,7 @@ pub fn analyze_dot(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
             let mut tot = 0.0;
             for row in 0..DOT_STRIP_HEIGHT {
                 let y = DOT_STRIP_HEIGHT * strip + row;
-                let v = read_pixel(img, dstpos, y, srgb);
+                let v = read_pixel(img, dstpos, y, &transfer, &lut);
                 tot += v - DARK as f64;
             }
 
@@ -120,7 +119,8 @@ pub fn analyze_dot(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
 ///
 /// The line pattern is a single bright column that, when upscaled, directly reveals
 /// the filter kernel shape as a connected curve.
-pub fn analyze_line(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
+pub fn analyze_line(img: &ImgRef<'_, u8>, transfer: Transfer) -> FilterCurve {
+    let lut = transfer.decode_lut();
     let w = img.width();
     let h = img.height();
     let scale_factor = w as f64 / LINE_SRC_WIDTH as f64;
@@ -139,7 +139,7 @@ pub fn analyze_line(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
             scanline
         };
 
-        let v = read_pixel(img, i, y, srgb);
+        let v = read_pixel(img, i, y, &transfer, &lut);
         let mut weight = (v - DARK as f64) / (BRIGHT as f64 - DARK as f64);
         tot += weight;
 
@@ -164,6 +164,28 @@ pub fn analyze_line(img: &ImgRef<'_, u8>, srgb: bool) -> FilterCurve {
     }
 }
 
+/// Reconstruct the full 2D point-spread function from a same-size resize of
+/// [`pattern::generate_impulse_pattern`], normalized to `[0, 1]` weights.
+/// Unlike [`analyze_dot`]/[`analyze_line`], which assume a separable
+/// horizontal kernel, this keeps every `(y, x)` sample so callers can test
+/// that assumption themselves (see [`crate::radial::detect`]).
+pub fn analyze_2d(img: &ImgRef<'_, u8>, transfer: Transfer) -> Vec<Vec<f64>> {
+    let lut = transfer.decode_lut();
+    let w = img.width();
+    let h = img.height();
+
+    (0..h)
+        .map(|y| {
+            (0..w)
+                .map(|x| {
+                    let v = read_pixel(img, x, y, &transfer, &lut);
+                    (v - DARK as f64) / (BRIGHT as f64 - DARK as f64)
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Expected target dimensions for the dot pattern resize.
 pub fn dot_target() -> (usize, usize) {
     (DOT_DST_WIDTH, DOT_DST_HEIGHT)
@@ -202,7 +224,7 @@ mod tests {
         let dot = pattern::generate_dot_pattern();
         let (tw, th) = dot_target();
         let resized = nn_resize(dot.as_ref(), tw, th);
-        let curve = analyze_dot(&resized.as_ref(), false);
+        let curve = analyze_dot(&resized.as_ref(), Transfer::Linear);
         assert!(!curve.points.is_empty());
         assert!(curve.scale_factor < 1.0);
     }
@@ -212,10 +234,67 @@ mod tests {
         let line = pattern::generate_line_pattern();
         let (tw, th) = line_target();
         let resized = nn_resize(line.as_ref(), tw, th);
-        let curve = analyze_line(&resized.as_ref(), false);
+        let curve = analyze_line(&resized.as_ref(), Transfer::Linear);
         assert_eq!(curve.points.len(), tw);
         assert!(curve.scale_factor > 1.0);
         // Area should be roughly 1.0 for a normalized filter
         assert!((curve.area - 1.0).abs() < 0.5, "area = {}", curve.area);
     }
+
+    /// Triangle-filter resize for testing: unlike [`nn_resize`], this
+    /// produces graduated in-between samples, which is what actually lets a
+    /// transfer curve's decode shape move the reconstructed area (a
+    /// nearest-neighbor resize only ever samples the pattern's two flat
+    /// DARK/BRIGHT levels, which any transfer decodes back to themselves
+    /// exactly, so it can't reveal a transfer-dependent difference at all).
+    fn triangle_resize(src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize) -> ImgVec<u8> {
+        const RADIUS: i64 = 3;
+        let tri = |d: f64| (1.0 - d.abs() / RADIUS as f64).max(0.0);
+        let mut dst = vec![0u8; dst_w * dst_h];
+        for y in 0..dst_h {
+            let sy = ((y as f64 + 0.5) * src.height() as f64 / dst_h as f64 - 0.5)
+                .round()
+                .clamp(0.0, (src.height() - 1) as f64) as usize;
+            for x in 0..dst_w {
+                let sx = (x as f64 + 0.5) * src.width() as f64 / dst_w as f64 - 0.5;
+                let cx = sx.round() as i64;
+                let mut total = 0.0;
+                let mut wsum = 0.0;
+                for kx in (cx - RADIUS - 1)..=(cx + RADIUS + 1) {
+                    let w = tri(kx as f64 - sx);
+                    if w <= 0.0 {
+                        continue;
+                    }
+                    let c = kx.clamp(0, src.width() as i64 - 1) as usize;
+                    total += w * src.buf()[sy * src.stride() + c] as f64;
+                    wsum += w;
+                }
+                let v = if wsum > 1e-9 { total / wsum } else { 0.0 };
+                dst[y * dst_w + x] = v.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+        ImgVec::new(dst, dst_w, dst_h)
+    }
+
+    #[test]
+    fn srgb_transfer_shifts_area_relative_to_linear() {
+        // A nearest-neighbor resize only ever samples the pattern's flat
+        // DARK/BRIGHT levels, which decode back to themselves under any
+        // transfer, so it can't exercise a transfer-dependent difference
+        // (see the note on `triangle_resize`). A real interpolating resize
+        // produces graduated in-between samples, and decoding those through
+        // sRGB's nonlinear curve shifts the reconstructed area well past
+        // floating-point noise.
+        let line = pattern::generate_line_pattern();
+        let (tw, th) = line_target();
+        let resized = triangle_resize(line.as_ref(), tw, th);
+        let linear = analyze_line(&resized.as_ref(), Transfer::Linear);
+        let srgb = analyze_line(&resized.as_ref(), Transfer::Srgb);
+        assert!(
+            (linear.area - srgb.area).abs() > 1e-6,
+            "linear area = {}, srgb area = {}",
+            linear.area,
+            srgb.area
+        );
+    }
 }
@@ -0,0 +1,206 @@
+//! Transfer functions (gamma/EOTF curves) for linearizing 8-bit samples
+//! before filter reconstruction.
+//!
+//! A resizer that gamma-corrects before resampling produces a measurably
+//! different reconstructed curve than one that blends raw encoded samples,
+//! so [`analyze::analyze_dot`](crate::analyze::analyze_dot) and
+//! [`analyze::analyze_line`](crate::analyze::analyze_line) need to know
+//! which transfer function the resizer-under-test assumed.
+
+/// A transfer function (opto-electronic / electro-optical) mapping between
+/// an encoded sample in `[0, 1]` and linear light.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transfer {
+    /// No conversion: samples are already linear.
+    Linear,
+    /// The piecewise sRGB EOTF/OETF.
+    Srgb,
+    /// A plain power-law gamma, `encoded = linear^(1/gamma)`.
+    Gamma(f64),
+    /// The BT.709 transfer function used by Rec.709 video.
+    Rec709,
+    /// A user-supplied decode table: entry `i` is the linear value for
+    /// encoded sample `i / (table.len() - 1)`, with values between
+    /// entries linearly interpolated. For a resizer whose curve doesn't
+    /// match any named transfer (e.g. a measured display EOTF).
+    CustomLut(Vec<f64>),
+}
+
+impl Transfer {
+    /// Decode an encoded sample in `[0, 1]` to linear light.
+    pub fn decode(&self, c: f64) -> f64 {
+        match self {
+            Self::Linear => c,
+            Self::Srgb => {
+                if c <= 0.04045 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            }
+            Self::Gamma(gamma) => c.powf(*gamma),
+            Self::Rec709 => {
+                if c < 0.081 {
+                    c / 4.5
+                } else {
+                    ((c + 0.099) / 1.099).powf(1.0 / 0.45)
+                }
+            }
+            Self::CustomLut(table) => sample_lut(table, c),
+        }
+    }
+
+    /// Encode a linear-light value in `[0, 1]` back to this transfer's domain.
+    pub fn encode(&self, linear: f64) -> f64 {
+        match self {
+            Self::Linear => linear,
+            Self::Srgb => {
+                if linear <= 0.0031308 {
+                    12.92 * linear
+                } else {
+                    1.055 * linear.powf(1.0 / 2.4) - 0.055
+                }
+            }
+            Self::Gamma(gamma) => linear.powf(1.0 / gamma),
+            Self::Rec709 => {
+                if linear < 0.018 {
+                    4.5 * linear
+                } else {
+                    1.099 * linear.powf(0.45) - 0.099
+                }
+            }
+            Self::CustomLut(table) => invert_lut(table, linear),
+        }
+    }
+
+    /// A precomputed 256-entry `u8 -> linear f32` decode table, for
+    /// hot loops that would otherwise re-evaluate [`Transfer::decode`]
+    /// per pixel.
+    pub fn decode_lut(&self) -> [f32; 256] {
+        let mut lut = [0.0f32; 256];
+        for (v, slot) in lut.iter_mut().enumerate() {
+            *slot = self.decode(v as f64 / 255.0) as f32;
+        }
+        lut
+    }
+
+    /// Encode a linear-light value in `[0, 1]` to an 8-bit sample.
+    pub fn encode_u8(&self, linear: f64) -> u8 {
+        (self.encode(linear) * 255.0).round().clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Linearly interpolate `table` at position `c` in `[0, 1]`, treating
+/// index `i` as sitting at `i / (table.len() - 1)`.
+fn sample_lut(table: &[f64], c: f64) -> f64 {
+    if table.len() < 2 {
+        return table.first().copied().unwrap_or(0.0);
+    }
+    let pos = c.clamp(0.0, 1.0) * (table.len() - 1) as f64;
+    let i0 = pos.floor() as usize;
+    let i1 = (i0 + 1).min(table.len() - 1);
+    let frac = pos - i0 as f64;
+    table[i0] * (1.0 - frac) + table[i1] * frac
+}
+
+/// Invert `table` (assumed monotonically non-decreasing, as produced by a
+/// real decode curve) to find the encoded sample whose interpolated value
+/// is `linear`, via binary search over the bracketing entries.
+fn invert_lut(table: &[f64], linear: f64) -> f64 {
+    if table.len() < 2 {
+        return 0.0;
+    }
+    let last = table.len() - 1;
+    if linear <= table[0] {
+        return 0.0;
+    }
+    if linear >= table[last] {
+        return 1.0;
+    }
+    let idx = table.partition_point(|&v| v < linear).clamp(1, last);
+    let (lo, hi) = (idx - 1, idx);
+    let span = table[hi] - table[lo];
+    let frac = if span.abs() < 1e-12 {
+        0.0
+    } else {
+        (linear - table[lo]) / span
+    };
+    (lo as f64 + frac) / last as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_is_identity() {
+        for v in [0.0, 0.2, 0.5, 1.0] {
+            assert_eq!(Transfer::Linear.decode(v), v);
+            assert_eq!(Transfer::Linear.encode(v), v);
+        }
+    }
+
+    #[test]
+    fn srgb_roundtrips() {
+        for v in 0..=255u8 {
+            let linear = Transfer::Srgb.decode(v as f64 / 255.0);
+            let back = Transfer::Srgb.encode_u8(linear);
+            assert!((back as i32 - v as i32).abs() <= 1, "v={v} back={back}");
+        }
+    }
+
+    #[test]
+    fn rec709_roundtrips() {
+        for v in 0..=255u8 {
+            let linear = Transfer::Rec709.decode(v as f64 / 255.0);
+            let back = Transfer::Rec709.encode_u8(linear);
+            assert!((back as i32 - v as i32).abs() <= 1, "v={v} back={back}");
+        }
+    }
+
+    #[test]
+    fn gamma_roundtrips() {
+        let t = Transfer::Gamma(2.2);
+        for v in 0..=255u8 {
+            let linear = t.decode(v as f64 / 255.0);
+            let back = t.encode_u8(linear);
+            assert!((back as i32 - v as i32).abs() <= 1, "v={v} back={back}");
+        }
+    }
+
+    #[test]
+    fn decode_lut_matches_decode() {
+        let t = Transfer::Srgb;
+        let lut = t.decode_lut();
+        for v in 0..=255u8 {
+            let expected = t.decode(v as f64 / 255.0) as f32;
+            assert_eq!(lut[v as usize], expected);
+        }
+    }
+
+    #[test]
+    fn custom_lut_matching_srgb_decodes_like_srgb() {
+        let table: Vec<f64> = (0..256)
+            .map(|i| Transfer::Srgb.decode(i as f64 / 255.0))
+            .collect();
+        let custom = Transfer::CustomLut(table);
+        for v in 0..=255u8 {
+            let c = v as f64 / 255.0;
+            let expected = Transfer::Srgb.decode(c);
+            assert!((custom.decode(c) - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn custom_lut_roundtrips() {
+        let table: Vec<f64> = (0..256)
+            .map(|i| Transfer::Gamma(2.2).decode(i as f64 / 255.0))
+            .collect();
+        let t = Transfer::CustomLut(table);
+        for v in 0..=255u8 {
+            let linear = t.decode(v as f64 / 255.0);
+            let back = t.encode_u8(linear);
+            assert!((back as i32 - v as i32).abs() <= 1, "v={v} back={back}");
+        }
+    }
+}
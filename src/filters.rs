@@ -12,6 +12,15 @@ pub enum KnownFilter {
     Lanczos3,
     Lanczos4,
     MitchellNetravali { b: f64, c: f64 },
+    /// Sinc windowed by a Hann window: `sinc(x) * (0.5 + 0.5*cos(pi*x/a))` for `|x| < a`.
+    Hann { a: f64 },
+    /// Sinc windowed by a Hamming window: `sinc(x) * (0.54 + 0.46*cos(pi*x/a))` for `|x| < a`.
+    Hamming { a: f64 },
+    /// Sinc windowed by a Blackman window for `|x| < a`.
+    Blackman { a: f64 },
+    /// Sinc windowed by a Kaiser window (modified Bessel function of the
+    /// first kind, order 0) with shape parameter `beta`, for `|x| < a`.
+    Kaiser { a: f64, beta: f64 },
 }
 
 impl KnownFilter {
@@ -27,6 +36,10 @@ impl KnownFilter {
             Self::Lanczos3 => "Lanczos3",
             Self::Lanczos4 => "Lanczos4",
             Self::MitchellNetravali { .. } => "Mitchell-Netravali",
+            Self::Hann { .. } => "Hann",
+            Self::Hamming { .. } => "Hamming",
+            Self::Blackman { .. } => "Blackman",
+            Self::Kaiser { .. } => "Kaiser",
         }
     }
 
@@ -40,6 +53,9 @@ impl KnownFilter {
             Self::Lanczos2 => 2.0,
             Self::Lanczos3 => 3.0,
             Self::Lanczos4 => 4.0,
+            Self::Hann { a } | Self::Hamming { a } | Self::Blackman { a } | Self::Kaiser { a, .. } => {
+                *a
+            }
         }
     }
 
@@ -55,6 +71,10 @@ impl KnownFilter {
             Self::Lanczos2 => lanczos(x, 2),
             Self::Lanczos3 => lanczos(x, 3),
             Self::Lanczos4 => lanczos(x, 4),
+            Self::Hann { a } => windowed_sinc(x, *a, window_hann),
+            Self::Hamming { a } => windowed_sinc(x, *a, window_hamming),
+            Self::Blackman { a } => windowed_sinc(x, *a, window_blackman),
+            Self::Kaiser { a, beta } => windowed_sinc(x, *a, |t| window_kaiser(t, *beta)),
         }
     }
 
@@ -70,6 +90,10 @@ impl KnownFilter {
             KnownFilter::Lanczos2,
             KnownFilter::Lanczos3,
             KnownFilter::Lanczos4,
+            KnownFilter::Hann { a: 3.0 },
+            KnownFilter::Hamming { a: 3.0 },
+            KnownFilter::Blackman { a: 3.0 },
+            KnownFilter::Kaiser { a: 3.0, beta: 6.0 },
         ]
     }
 }
@@ -78,6 +102,10 @@ impl std::fmt::Display for KnownFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::MitchellNetravali { b, c } => write!(f, "Mitchell-Netravali(B={b:.3}, C={c:.3})"),
+            Self::Hann { a } => write!(f, "Hann(a={a:.2})"),
+            Self::Hamming { a } => write!(f, "Hamming(a={a:.2})"),
+            Self::Blackman { a } => write!(f, "Blackman(a={a:.2})"),
+            Self::Kaiser { a, beta } => write!(f, "Kaiser(a={a:.2}, beta={beta:.2})"),
             other => f.write_str(other.name()),
         }
     }
@@ -144,6 +172,50 @@ fn lanczos(x: f64, n: u32) -> f64 {
     }
 }
 
+/// A sinc kernel apodized by `window`, zero beyond support `a`.
+/// Lanczos is the special case where the window is itself `sinc(t)`.
+fn windowed_sinc(x: f64, a: f64, window: impl Fn(f64) -> f64) -> f64 {
+    let ax = x.abs();
+    if ax < a {
+        sinc(x) * window(x / a)
+    } else {
+        0.0
+    }
+}
+
+fn window_hann(t: f64) -> f64 {
+    0.5 + 0.5 * (PI * t).cos()
+}
+
+fn window_hamming(t: f64) -> f64 {
+    0.54 + 0.46 * (PI * t).cos()
+}
+
+fn window_blackman(t: f64) -> f64 {
+    0.42 + 0.5 * (PI * t).cos() + 0.08 * (2.0 * PI * t).cos()
+}
+
+fn window_kaiser(t: f64, beta: f64) -> f64 {
+    bessel_i0(beta * (1.0 - t * t).max(0.0).sqrt()) / bessel_i0(beta)
+}
+
+/// Modified Bessel function of the first kind, order 0, via its power
+/// series `I0(z) = sum_k ((z/2)^(2k) / (k!)^2)`, truncated once a term
+/// falls below ~1e-12.
+fn bessel_i0(z: f64) -> f64 {
+    let half_z_sq = (z / 2.0).powi(2);
+    let mut term = 1.0_f64;
+    let mut sum = 1.0_f64;
+    for k in 1..=200u32 {
+        term *= half_z_sq / (k as f64 * k as f64);
+        sum += term;
+        if term.abs() < 1e-12 {
+            break;
+        }
+    }
+    sum
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +295,35 @@ mod tests {
             "B-spline partition of unity: sum = {sum}"
         );
     }
+
+    #[test]
+    fn windowed_sinc_filters_interpolate_at_zero() {
+        for f in &[
+            KnownFilter::Hann { a: 3.0 },
+            KnownFilter::Hamming { a: 3.0 },
+            KnownFilter::Blackman { a: 3.0 },
+            KnownFilter::Kaiser { a: 3.0, beta: 6.0 },
+        ] {
+            let v = f.evaluate(0.0);
+            assert!((v - 1.0).abs() < 1e-10, "{}: f(0) = {v}", f.name());
+            assert_eq!(f.support(), 3.0);
+        }
+    }
+
+    #[test]
+    fn windowed_sinc_symmetry() {
+        for &x in &[0.3, 1.7, 2.9] {
+            let pos = KnownFilter::Kaiser { a: 3.0, beta: 6.0 }.evaluate(x);
+            let neg = KnownFilter::Kaiser { a: 3.0, beta: 6.0 }.evaluate(-x);
+            assert!((pos - neg).abs() < 1e-10, "Kaiser not symmetric at {x}");
+        }
+    }
+
+    #[test]
+    fn bessel_i0_known_values() {
+        // I0(0) = 1.
+        assert!((bessel_i0(0.0) - 1.0).abs() < 1e-12);
+        // I0(1) ~= 1.2660658...
+        assert!((bessel_i0(1.0) - 1.2660658777520084).abs() < 1e-9);
+    }
 }
@@ -47,178 +47,590 @@ fn ycoord(iy: f64) -> i32 {
     (0.5 + ZERO_Y + iy * UNIT_Y) as i32
 }
 
-fn set_pixel(buf: &mut [RGB8], x: i32, y: i32, color: RGB8) {
-    if x >= 0 && x < WIDTH as i32 && y >= 0 && y < HEIGHT as i32 {
-        buf[y as usize * WIDTH + x as usize] = color;
-    }
+/// A pixel buffer paired with its dimensions. Bundling them here means the
+/// drawing methods below take `width`/`height` once, as `self`, instead of
+/// as a pair of positional arguments threaded through every call.
+struct Canvas<'a> {
+    buf: &'a mut [RGB8],
+    width: usize,
+    height: usize,
 }
 
-fn draw_line(buf: &mut [RGB8], x0: i32, y0: i32, x1: i32, y1: i32, color: RGB8) {
-    let dx = (x1 - x0).abs();
-    let dy = -(y1 - y0).abs();
-    let sx: i32 = if x0 < x1 { 1 } else { -1 };
-    let sy: i32 = if y0 < y1 { 1 } else { -1 };
-    let mut err = dx + dy;
-    let mut x = x0;
-    let mut y = y0;
-
-    loop {
-        set_pixel(buf, x, y, color);
-        if x == x1 && y == y1 {
-            break;
+impl<'a> Canvas<'a> {
+    fn set_pixel(&mut self, x: i32, y: i32, color: RGB8) {
+        if x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32 {
+            self.buf[y as usize * self.width + x as usize] = color;
         }
-        let e2 = 2 * err;
-        if e2 >= dy {
-            if x == x1 {
+    }
+
+    fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: RGB8) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx: i32 = if x0 < x1 { 1 } else { -1 };
+        let sy: i32 = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let mut x = x0;
+        let mut y = y0;
+
+        loop {
+            self.set_pixel(x, y, color);
+            if x == x1 && y == y1 {
                 break;
             }
-            err += dy;
-            x += sx;
+            let e2 = 2 * err;
+            if e2 >= dy {
+                if x == x1 {
+                    break;
+                }
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                if y == y1 {
+                    break;
+                }
+                err += dx;
+                y += sy;
+            }
         }
-        if e2 <= dx {
-            if y == y1 {
+    }
+
+    fn draw_dashed_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: RGB8) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx: i32 = if x0 < x1 { 1 } else { -1 };
+        let sy: i32 = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let mut x = x0;
+        let mut y = y0;
+        let mut step = 0u32;
+
+        loop {
+            // 4 on, 4 off pattern matching gdImageDashedLine
+            if step % 8 < 4 {
+                self.set_pixel(x, y, color);
+            }
+            step += 1;
+            if x == x1 && y == y1 {
                 break;
             }
-            err += dx;
-            y += sy;
+            let e2 = 2 * err;
+            if e2 >= dy {
+                if x == x1 {
+                    break;
+                }
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                if y == y1 {
+                    break;
+                }
+                err += dx;
+                y += sy;
+            }
         }
     }
-}
 
-fn draw_dashed_line(buf: &mut [RGB8], x0: i32, y0: i32, x1: i32, y1: i32, color: RGB8) {
-    let dx = (x1 - x0).abs();
-    let dy = -(y1 - y0).abs();
-    let sx: i32 = if x0 < x1 { 1 } else { -1 };
-    let sy: i32 = if y0 < y1 { 1 } else { -1 };
-    let mut err = dx + dy;
-    let mut x = x0;
-    let mut y = y0;
-    let mut step = 0u32;
+    fn draw_grid(&mut self, xcoord: impl Fn(f64) -> i32, ycoord: impl Fn(f64) -> i32) {
+        // Dashed lines at half-integers
+        for i in -10..=10 {
+            let hx = xcoord(0.5 + i as f64);
+            self.draw_dashed_line(hx, 0, hx, self.height as i32 - 1, GRID_GRAY);
+            let hy = ycoord(0.5 + i as f64);
+            self.draw_dashed_line(0, hy, self.width as i32 - 1, hy, GRID_GRAY);
+        }
 
-    loop {
-        // 4 on, 4 off pattern matching gdImageDashedLine
-        if step % 8 < 4 {
-            set_pixel(buf, x, y, color);
+        // Solid lines at integers
+        for i in -10..=10 {
+            let ix = xcoord(i as f64);
+            self.draw_line(ix, 0, ix, self.height as i32 - 1, GRID_GRAY);
+            let iy = ycoord(i as f64);
+            self.draw_line(0, iy, self.width as i32 - 1, iy, GRID_GRAY);
         }
-        step += 1;
-        if x == x1 && y == y1 {
-            break;
+
+        // Axes
+        let ax = xcoord(0.0);
+        self.draw_line(ax, 0, ax, self.height as i32 - 1, BLACK);
+        let ay = ycoord(0.0);
+        self.draw_line(0, ay, self.width as i32 - 1, ay, BLACK);
+    }
+
+    fn draw_border(&mut self, color: RGB8) {
+        let w = self.width as i32;
+        let h = self.height as i32;
+        self.draw_line(0, 0, w - 1, 0, color);
+        self.draw_line(0, h - 1, w - 1, h - 1, color);
+        self.draw_line(0, 0, 0, h - 1, color);
+        self.draw_line(w - 1, 0, w - 1, h - 1, color);
+    }
+
+    fn plot_scatter(
+        &mut self,
+        points: &[(f64, f64)],
+        xcoord: impl Fn(f64) -> i32,
+        ycoord: impl Fn(f64) -> i32,
+        color: RGB8,
+    ) {
+        for &(x, y) in points {
+            let px = xcoord(x);
+            let py = ycoord(y);
+            self.set_pixel(px, py, color);
         }
-        let e2 = 2 * err;
-        if e2 >= dy {
-            if x == x1 {
-                break;
+    }
+
+    fn plot_connected(
+        &mut self,
+        points: &[(f64, f64)],
+        xcoord: impl Fn(f64) -> i32,
+        ycoord: impl Fn(f64) -> i32,
+        color: RGB8,
+    ) {
+        let mut last: Option<(i32, i32)> = None;
+        for &(x, y) in points {
+            let px = xcoord(x);
+            let py = ycoord(y);
+            if let Some((lx, ly)) = last {
+                self.draw_line(lx, ly, px, py, color);
             }
-            err += dy;
-            x += sx;
+            last = Some((px, py));
         }
-        if e2 <= dx {
-            if y == y1 {
-                break;
+    }
+
+    fn plot_reference(
+        &mut self,
+        x_range: (f64, f64),
+        xcoord: impl Fn(f64) -> i32,
+        ycoord: impl Fn(f64) -> i32,
+        filter: KnownFilter,
+        color: RGB8,
+    ) {
+        // Sample the reference filter densely across the visible range.
+        let (x_min, x_max) = x_range;
+
+        let steps = self.width * 2;
+        let mut last: Option<(i32, i32)> = None;
+        for i in 0..=steps {
+            let x = x_min + (x_max - x_min) * i as f64 / steps as f64;
+            let y = filter.evaluate(x);
+            let px = xcoord(x);
+            let py = ycoord(y);
+            if let Some((lx, ly)) = last {
+                self.draw_line(lx, ly, px, py, color);
+            }
+            last = Some((px, py));
+        }
+    }
+
+    fn draw_text(&mut self, x: i32, y: i32, text: &str, color: RGB8) {
+        for (i, c) in text.chars().enumerate() {
+            let gx = x + i as i32 * GLYPH_ADVANCE;
+            for (row, bits) in glyph_rows(c).iter().enumerate() {
+                for col in 0..GLYPH_W {
+                    let mask: u8 = 1u8 << (GLYPH_W - 1 - col) as u32;
+                    if bits & mask != 0 {
+                        self.set_pixel(gx + col, y + row as i32, color);
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw_axis_tick_labels(&mut self, config: &GraphConfig) {
+        let (x_min, x_max) = config.x_range;
+        for i in x_min.ceil() as i32..=x_max.floor() as i32 {
+            let px = config.xcoord(i as f64);
+            let label = format!("{i}");
+            self.draw_text(
+                px - (label.len() as i32 * GLYPH_ADVANCE) / 2,
+                config.ycoord(0.0) + 3,
+                &label,
+                BLACK,
+            );
+        }
+
+        let (y_min, y_max) = config.y_range;
+        for i in y_min.ceil() as i32..=y_max.floor() as i32 {
+            if i == 0 {
+                continue; // overlaps the x-axis "0" label
+            }
+            let py = config.ycoord(i as f64);
+            let label = format!("{i}");
+            self.draw_text(
+                config.xcoord(0.0) - (label.len() as i32 + 1) * GLYPH_ADVANCE,
+                py - GLYPH_H / 2,
+                &label,
+                BLACK,
+            );
+        }
+    }
+
+    /// Draw a legend box in the top-right corner: one swatch + label per overlay.
+    fn draw_legend(&mut self, references: &[ReferenceOverlay]) {
+        if references.is_empty() {
+            return;
+        }
+
+        let row_height = GLYPH_H + 4;
+        let box_height = references.len() as i32 * row_height + 4;
+        let max_label_len = references.iter().map(|r| r.label.len()).max().unwrap_or(0);
+        let box_width = 10 + max_label_len as i32 * GLYPH_ADVANCE + 6;
+
+        let x0 = self.width as i32 - box_width - 4;
+        let y0 = 4;
+
+        self.draw_line(x0, y0, x0 + box_width, y0, BLACK);
+        self.draw_line(x0, y0 + box_height, x0 + box_width, y0 + box_height, BLACK);
+        self.draw_line(x0, y0, x0, y0 + box_height, BLACK);
+        self.draw_line(
+            x0 + box_width,
+            y0,
+            x0 + box_width,
+            y0 + box_height,
+            BLACK,
+        );
+
+        for (i, overlay) in references.iter().enumerate() {
+            let y = y0 + 2 + i as i32 * row_height;
+            for dy in 0..GLYPH_H {
+                for dx in 0..5 {
+                    self.set_pixel(x0 + 3 + dx, y + dy, overlay.color);
+                }
             }
-            err += dx;
-            y += sy;
+            self.draw_text(x0 + 3 + 5 + 3, y, &overlay.label, BLACK);
         }
     }
 }
 
-fn draw_grid(buf: &mut [RGB8]) {
-    // Dashed lines at half-integers
+/// Render a scope graph showing the reconstructed filter curve(s).
+pub fn render(
+    downscale: Option<&FilterCurve>,
+    upscale: Option<&FilterCurve>,
+    reference: Option<KnownFilter>,
+) -> ImgVec<RGB8> {
+    let mut buf = vec![WHITE; WIDTH * HEIGHT];
+    let mut canvas = Canvas {
+        buf: &mut buf,
+        width: WIDTH,
+        height: HEIGHT,
+    };
+
+    canvas.draw_grid(xcoord, ycoord);
+
+    if let Some(filter) = reference {
+        let x_range = (-ZERO_X / UNIT_X, (WIDTH as f64 - ZERO_X) / UNIT_X);
+        canvas.plot_reference(x_range, xcoord, ycoord, filter, REF_LIGHT);
+    }
+
+    if let Some(ds) = downscale {
+        canvas.plot_scatter(&ds.points, xcoord, ycoord, SCATTER_BLUE);
+    }
+
+    if let Some(us) = upscale {
+        canvas.plot_connected(&us.points, xcoord, ycoord, LINE_RED);
+    }
+
+    canvas.draw_border(BORDER_GREEN);
+
+    ImgVec::new(buf, WIDTH, HEIGHT)
+}
+
+fn svg_color(c: RGB8) -> String {
+    format!("rgb({},{},{})", c.r, c.g, c.b)
+}
+
+fn svg_line(x0: i32, y0: i32, x1: i32, y1: i32, color: &str, dashed: bool) -> String {
+    let dash = if dashed {
+        " stroke-dasharray=\"4,4\""
+    } else {
+        ""
+    };
+    format!("<line x1=\"{x0}\" y1=\"{y0}\" x2=\"{x1}\" y2=\"{y1}\" stroke=\"{color}\"{dash}/>\n")
+}
+
+fn svg_polyline(points: &[(f64, f64)], color: &str) -> String {
+    let pts: Vec<String> = points
+        .iter()
+        .map(|&(x, y)| format!("{},{}", xcoord(x), ycoord(y)))
+        .collect();
+    format!(
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\"/>\n",
+        pts.join(" "),
+        color
+    )
+}
+
+fn svg_reference_polyline(filter: KnownFilter, color: &str) -> String {
+    let x_min = -ZERO_X / UNIT_X;
+    let x_max = (WIDTH as f64 - ZERO_X) / UNIT_X;
+    let steps = WIDTH * 2;
+    let points: Vec<(f64, f64)> = (0..=steps)
+        .map(|i| {
+            let x = x_min + (x_max - x_min) * i as f64 / steps as f64;
+            (x, filter.evaluate(x))
+        })
+        .collect();
+    svg_polyline(&points, color)
+}
+
+/// Render the same scope graph as [`render`], but as an SVG document: the
+/// grid, axes, scatter/connected curves, and reference overlay are emitted
+/// as `<line>`/`<circle>`/`<polyline>` elements using the same `xcoord`/
+/// `ycoord` mapping and color constants, instead of being rasterized. Vector
+/// output stays crisp when zoomed, unlike the Bresenham-rasterized
+/// [`render`], which aliases badly at a filter's sharp zero-crossings.
+pub fn render_svg(
+    downscale: Option<&FilterCurve>,
+    upscale: Option<&FilterCurve>,
+    reference: Option<KnownFilter>,
+) -> String {
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\">\n"
+    ));
+    svg.push_str(&format!(
+        "<rect x=\"0\" y=\"0\" width=\"{WIDTH}\" height=\"{HEIGHT}\" fill=\"{}\"/>\n",
+        svg_color(WHITE)
+    ));
+
+    let grid_color = svg_color(GRID_GRAY);
     for i in -10..=10 {
         let hx = xcoord(0.5 + i as f64);
-        draw_dashed_line(buf, hx, 0, hx, HEIGHT as i32 - 1, GRID_GRAY);
+        svg.push_str(&svg_line(hx, 0, hx, HEIGHT as i32 - 1, &grid_color, true));
         let hy = ycoord(0.5 + i as f64);
-        draw_dashed_line(buf, 0, hy, WIDTH as i32 - 1, hy, GRID_GRAY);
+        svg.push_str(&svg_line(0, hy, WIDTH as i32 - 1, hy, &grid_color, true));
     }
-
-    // Solid lines at integers
     for i in -10..=10 {
         let ix = xcoord(i as f64);
-        draw_line(buf, ix, 0, ix, HEIGHT as i32 - 1, GRID_GRAY);
+        svg.push_str(&svg_line(ix, 0, ix, HEIGHT as i32 - 1, &grid_color, false));
         let iy = ycoord(i as f64);
-        draw_line(buf, 0, iy, WIDTH as i32 - 1, iy, GRID_GRAY);
+        svg.push_str(&svg_line(0, iy, WIDTH as i32 - 1, iy, &grid_color, false));
     }
 
-    // Axes
+    let black = svg_color(BLACK);
     let ax = xcoord(0.0);
-    draw_line(buf, ax, 0, ax, HEIGHT as i32 - 1, BLACK);
+    svg.push_str(&svg_line(ax, 0, ax, HEIGHT as i32 - 1, &black, false));
     let ay = ycoord(0.0);
-    draw_line(buf, 0, ay, WIDTH as i32 - 1, ay, BLACK);
+    svg.push_str(&svg_line(0, ay, WIDTH as i32 - 1, ay, &black, false));
+
+    if let Some(filter) = reference {
+        svg.push_str(&svg_reference_polyline(filter, &svg_color(REF_LIGHT)));
+    }
+
+    if let Some(ds) = downscale {
+        let color = svg_color(SCATTER_BLUE);
+        for &(x, y) in &ds.points {
+            svg.push_str(&format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"1.5\" fill=\"{}\"/>\n",
+                xcoord(x),
+                ycoord(y),
+                color
+            ));
+        }
+    }
+
+    if let Some(us) = upscale {
+        svg.push_str(&svg_polyline(&us.points, &svg_color(LINE_RED)));
+    }
+
+    svg.push_str(&format!(
+        "<rect x=\"0\" y=\"0\" width=\"{WIDTH}\" height=\"{HEIGHT}\" fill=\"none\" stroke=\"{}\"/>\n",
+        svg_color(BORDER_GREEN)
+    ));
+
+    svg.push_str("</svg>\n");
+    svg
 }
 
-fn draw_border(buf: &mut [RGB8], color: RGB8) {
-    let w = WIDTH as i32;
-    let h = HEIGHT as i32;
-    draw_line(buf, 0, 0, w - 1, 0, color);
-    draw_line(buf, 0, h - 1, w - 1, h - 1, color);
-    draw_line(buf, 0, 0, 0, h - 1, color);
-    draw_line(buf, w - 1, 0, w - 1, h - 1, color);
+/// Canvas size, visible logical axis ranges, and output scale for [`render_with`].
+///
+/// `Default` reproduces the exact framing [`render`] uses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphConfig {
+    pub width: usize,
+    pub height: usize,
+    /// Visible logical x range (filter offset, in source-pixel units), `(min, max)`.
+    pub x_range: (f64, f64),
+    /// Visible logical y range (filter weight), `(min, max)`.
+    pub y_range: (f64, f64),
+    /// Scale factor applied to `width`/`height` for higher-resolution output.
+    pub dpi_scale: f64,
 }
 
-fn plot_scatter(buf: &mut [RGB8], points: &[(f64, f64)], color: RGB8) {
-    for &(x, y) in points {
-        let px = xcoord(x);
-        let py = ycoord(y);
-        set_pixel(buf, px, py, color);
+impl Default for GraphConfig {
+    fn default() -> Self {
+        Self {
+            width: WIDTH,
+            height: HEIGHT,
+            x_range: (-ZERO_X / UNIT_X, (WIDTH as f64 - ZERO_X) / UNIT_X),
+            y_range: ((HEIGHT as f64 - ZERO_Y) / UNIT_Y, -ZERO_Y / UNIT_Y),
+            dpi_scale: 1.0,
+        }
     }
 }
 
-fn plot_connected(buf: &mut [RGB8], points: &[(f64, f64)], color: RGB8) {
-    let mut last: Option<(i32, i32)> = None;
-    for &(x, y) in points {
-        let px = xcoord(x);
-        let py = ycoord(y);
-        if let Some((lx, ly)) = last {
-            draw_line(buf, lx, ly, px, py, color);
-        }
-        last = Some((px, py));
+impl GraphConfig {
+    fn pixel_dims(&self) -> (usize, usize) {
+        (
+            (((self.width as f64) * self.dpi_scale).round() as usize).max(1),
+            (((self.height as f64) * self.dpi_scale).round() as usize).max(1),
+        )
+    }
+
+    fn xcoord(&self, ix: f64) -> i32 {
+        let (w, _) = self.pixel_dims();
+        let (x_min, x_max) = self.x_range;
+        (0.5 + (ix - x_min) / (x_max - x_min) * w as f64) as i32
+    }
+
+    fn ycoord(&self, iy: f64) -> i32 {
+        let (_, h) = self.pixel_dims();
+        let (y_min, y_max) = self.y_range;
+        (0.5 + (y_max - iy) / (y_max - y_min) * h as f64) as i32
     }
 }
 
-fn plot_reference(buf: &mut [RGB8], filter: KnownFilter, color: RGB8) {
-    // Sample the reference filter densely across the visible range.
-    let x_min = -ZERO_X / UNIT_X; // leftmost visible logical x
-    let x_max = (WIDTH as f64 - ZERO_X) / UNIT_X; // rightmost visible logical x
+/// A reference filter overlay for [`render_with`]: what to draw, in what
+/// color, captioned by `label` in the legend.
+#[derive(Debug, Clone)]
+pub struct ReferenceOverlay {
+    pub filter: KnownFilter,
+    pub color: RGB8,
+    pub label: String,
+}
 
-    let steps = WIDTH * 2;
-    let mut last: Option<(i32, i32)> = None;
-    for i in 0..=steps {
-        let x = x_min + (x_max - x_min) * i as f64 / steps as f64;
-        let y = filter.evaluate(x);
-        let px = xcoord(x);
-        let py = ycoord(y);
-        if let Some((lx, ly)) = last {
-            draw_line(buf, lx, ly, px, py, color);
+impl ReferenceOverlay {
+    pub fn new(filter: KnownFilter, color: RGB8, label: impl Into<String>) -> Self {
+        Self {
+            filter,
+            color,
+            label: label.into(),
         }
-        last = Some((px, py));
     }
 }
 
-/// Render a scope graph showing the reconstructed filter curve(s).
-pub fn render(
+/// A small palette cycled across overlays that don't specify their own color.
+const OVERLAY_PALETTE: &[RGB8] = &[
+    RGB8 { r: 0, g: 150, b: 0 },
+    RGB8 { r: 200, g: 120, b: 0 },
+    RGB8 { r: 150, g: 0, b: 150 },
+    RGB8 { r: 0, g: 130, b: 180 },
+    RGB8 { r: 180, g: 0, b: 0 },
+];
+
+/// Render a scope graph with a configurable canvas/range and any number of
+/// labeled reference overlays, each drawn in its own color with a legend
+/// entry and a swatch. Tick labels on both axes are drawn numerically.
+pub fn render_with(
     downscale: Option<&FilterCurve>,
     upscale: Option<&FilterCurve>,
-    reference: Option<KnownFilter>,
+    references: &[ReferenceOverlay],
+    config: &GraphConfig,
 ) -> ImgVec<RGB8> {
-    let mut buf = vec![WHITE; WIDTH * HEIGHT];
+    let (w, h) = config.pixel_dims();
+    let mut buf = vec![WHITE; w * h];
+    let mut canvas = Canvas {
+        buf: &mut buf,
+        width: w,
+        height: h,
+    };
 
-    draw_grid(&mut buf);
+    let xcoord = |ix: f64| config.xcoord(ix);
+    let ycoord = |iy: f64| config.ycoord(iy);
 
-    if let Some(filter) = reference {
-        plot_reference(&mut buf, filter, REF_LIGHT);
+    canvas.draw_grid(xcoord, ycoord);
+    canvas.draw_axis_tick_labels(config);
+
+    for overlay in references {
+        canvas.plot_reference(config.x_range, xcoord, ycoord, overlay.filter, overlay.color);
     }
 
     if let Some(ds) = downscale {
-        plot_scatter(&mut buf, &ds.points, SCATTER_BLUE);
+        canvas.plot_scatter(&ds.points, xcoord, ycoord, SCATTER_BLUE);
     }
 
     if let Some(us) = upscale {
-        plot_connected(&mut buf, &us.points, LINE_RED);
+        canvas.plot_connected(&us.points, xcoord, ycoord, LINE_RED);
     }
 
-    draw_border(&mut buf, BORDER_GREEN);
+    canvas.draw_border(BORDER_GREEN);
+    canvas.draw_legend(references);
 
-    ImgVec::new(buf, WIDTH, HEIGHT)
+    ImgVec::new(buf, w, h)
+}
+
+const GLYPH_W: i32 = 3;
+const GLYPH_H: i32 = 5;
+const GLYPH_ADVANCE: i32 = GLYPH_W + 1;
+
+/// Bitmap for one glyph: 5 rows, each the low 3 bits encoding left/mid/right
+/// columns (bit 2 = leftmost). Unsupported characters render blank.
+fn glyph_rows(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+        ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+        '=' => [0b000, 0b111, 0b000, 0b111, 0b000],
+        ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b101, 0b111, 0b111, 0b111, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b111, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        _ => [0b000, 0b000, 0b000, 0b000, 0b000],
+    }
+}
+
+fn reference_label(filter: &KnownFilter) -> String {
+    filter.to_string()
+}
+
+/// One overlay per filter in `filters`, in order, each paired with a color
+/// cycled from [`OVERLAY_PALETTE`] and labeled with its display name; used
+/// to build the overlays for
+/// `AnalysisResult::render_graph_top`.
+pub(crate) fn overlays_for(filters: impl Iterator<Item = KnownFilter>) -> Vec<ReferenceOverlay> {
+    filters
+        .enumerate()
+        .map(|(i, filter)| {
+            let color = OVERLAY_PALETTE[i % OVERLAY_PALETTE.len()];
+            let label = reference_label(&filter);
+            ReferenceOverlay::new(filter, color, label)
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -241,4 +653,86 @@ mod tests {
         assert_eq!(ycoord(0.0), 220);
         assert_eq!(ycoord(1.0), 20);
     }
+
+    #[test]
+    fn graph_config_default_matches_render() {
+        let cfg = GraphConfig::default();
+        assert_eq!(cfg.xcoord(0.0), xcoord(0.0));
+        assert_eq!(cfg.xcoord(1.0), xcoord(1.0));
+        assert_eq!(cfg.ycoord(0.0), ycoord(0.0));
+        assert_eq!(cfg.ycoord(1.0), ycoord(1.0));
+    }
+
+    #[test]
+    fn render_with_matches_render_dimensions_for_default_config() {
+        let line = crate::generate_line_pattern();
+        let resized = crate::perfect_resize(line.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        let curve = crate::analyze::analyze_line(&resized.as_ref(), crate::Transfer::Linear);
+
+        let plain = render(None, Some(&curve), None);
+        let with = render_with(None, Some(&curve), &[], &GraphConfig::default());
+        assert_eq!(plain.width(), with.width());
+        assert_eq!(plain.height(), with.height());
+    }
+
+    #[test]
+    fn render_with_draws_legend_box() {
+        let overlays = vec![ReferenceOverlay::new(
+            KnownFilter::Box,
+            RGB8 { r: 0, g: 150, b: 0 },
+            "Box",
+        )];
+        let img = render_with(None, None, &overlays, &GraphConfig::default());
+        // The legend box's black top edge sits on row 4 near the right
+        // side, where the grid and border draw nothing black.
+        let has_legend_edge = img
+            .buf()
+            .iter()
+            .skip(4 * WIDTH + WIDTH - 50)
+            .take(50)
+            .any(|&p| p == BLACK);
+        assert!(has_legend_edge, "expected legend markings near top-right");
+    }
+
+    #[test]
+    fn overlays_for_builds_one_entry_per_filter() {
+        let overlays = overlays_for([KnownFilter::Box, KnownFilter::Triangle].into_iter());
+        assert_eq!(overlays.len(), 2);
+        assert_eq!(overlays[0].label, "Box");
+        assert_eq!(overlays[1].label, "Triangle");
+    }
+
+    #[test]
+    fn render_svg_is_well_formed() {
+        let svg = render_svg(None, None, None);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(&format!("width=\"{WIDTH}\"")));
+        assert!(svg.contains(&format!("height=\"{HEIGHT}\"")));
+    }
+
+    #[test]
+    fn render_svg_emits_one_circle_per_scatter_point() {
+        let dot = FilterCurve {
+            points: vec![(-1.0, 0.1), (0.0, 1.0), (1.0, 0.1)],
+            area: 0.0,
+            scale_factor: 0.5,
+            is_scatter: true,
+        };
+        let svg = render_svg(Some(&dot), None, None);
+        assert_eq!(svg.matches("<circle").count(), dot.points.len());
+    }
+
+    #[test]
+    fn render_svg_emits_polyline_for_connected_curve() {
+        let line = FilterCurve {
+            points: vec![(-1.0, 0.0), (0.0, 1.0), (1.0, 0.0)],
+            area: 1.0,
+            scale_factor: 2.0,
+            is_scatter: false,
+        };
+        let svg = render_svg(None, Some(&line), Some(KnownFilter::Box));
+        // One polyline for the reference curve, one for the connected curve.
+        assert_eq!(svg.matches("<polyline").count(), 2);
+    }
 }
@@ -1,4 +1,5 @@
-use imgref::ImgVec;
+use imgref::{ImgRef, ImgVec};
+use rgb::RGBA8;
 
 // Dot pattern constants (matching C source exactly)
 pub const DOT_SRC_WIDTH: usize = 557;
@@ -21,6 +22,16 @@ pub const LINE_DST_HEIGHT: usize = LINE_SRC_HEIGHT; // 15
 pub const DARK: u8 = 50;
 pub const BRIGHT: u8 = 250;
 
+// Impulse grid pattern constants, for 2D (non-separable) kernel probing.
+pub const IMPULSE_GRID_SPACING: usize = 24;
+pub const IMPULSE_GRID_MARGIN: usize = 12;
+pub const IMPULSE_GRID_SIZE: usize = 145;
+
+// Single-impulse pattern constants, for full 2D point-spread-function
+// extraction (tensor vs radial/cylindrical probing).
+pub const IMPULSE_SIZE: usize = 33;
+pub const IMPULSE_CENTER: usize = IMPULSE_SIZE / 2; // 16
+
 /// Generate the dot test pattern for downscale analysis.
 /// 557x275 grayscale image with bright dots at phase-offset positions per strip.
 pub fn generate_dot_pattern() -> ImgVec<u8> {
@@ -64,10 +75,134 @@ pub fn generate_line_pattern() -> ImgVec<u8> {
 /// Generate the edge test pattern for edge handling detection.
 /// 15x15 grayscale image with a bright column at x=1 (near left edge).
 pub fn generate_edge_pattern() -> ImgVec<u8> {
+    column_pattern(1)
+}
+
+/// Mirror of [`generate_edge_pattern`], with the bright column near the right
+/// edge instead, for probing right-boundary handling.
+pub fn generate_edge_pattern_right() -> ImgVec<u8> {
+    column_pattern(LINE_SRC_WIDTH - 2)
+}
+
+/// Transpose of [`generate_edge_pattern`], with a bright row near the top
+/// edge instead of a bright column, for probing top-boundary handling.
+pub fn generate_edge_pattern_top() -> ImgVec<u8> {
+    row_pattern(1)
+}
+
+/// Transpose of [`generate_edge_pattern_right`], with a bright row near the
+/// bottom edge, for probing bottom-boundary handling.
+pub fn generate_edge_pattern_bottom() -> ImgVec<u8> {
+    row_pattern(LINE_SRC_HEIGHT - 2)
+}
+
+/// Generate the alpha test pattern for premultiplied-vs-straight compositing
+/// analysis. 15x15 RGBA image: an opaque white column at the center (x=7)
+/// over a fully transparent, saturated-magenta background, so edge pixels
+/// reveal whether the resizer composites in premultiplied or straight alpha.
+pub fn generate_alpha_pattern() -> ImgVec<RGBA8> {
+    let middle = LINE_SRC_WIDTH / 2; // 7
+    let background = RGBA8::new(255, 0, 255, 0);
+    let foreground = RGBA8::new(255, 255, 255, 255);
+    let mut pixels = vec![background; LINE_SRC_WIDTH * LINE_SRC_HEIGHT];
+
+    for y in 0..LINE_SRC_HEIGHT {
+        pixels[y * LINE_SRC_WIDTH + middle] = foreground;
+    }
+
+    ImgVec::new(pixels, LINE_SRC_WIDTH, LINE_SRC_HEIGHT)
+}
+
+/// Swap rows and columns. Used to probe the vertical-axis kernel by
+/// reusing the horizontal test patterns: transpose the source so the
+/// resizer acts on what was previously the untouched axis, and the
+/// caller transposes the resized output back before handing it to the
+/// same horizontal reconstruction math.
+pub(crate) fn transpose(img: &ImgRef<'_, u8>) -> ImgVec<u8> {
+    let w = img.width();
+    let h = img.height();
+    let mut pixels = vec![0u8; w * h];
+
+    for y in 0..h {
+        for x in 0..w {
+            pixels[x * h + y] = img.buf()[y * img.stride() + x];
+        }
+    }
+
+    ImgVec::new(pixels, h, w)
+}
+
+/// Transpose of [`generate_dot_pattern`]: phase varies down columns
+/// instead of across rows, for probing the vertical-axis kernel.
+pub fn generate_dot_pattern_vertical() -> ImgVec<u8> {
+    transpose(&generate_dot_pattern().as_ref())
+}
+
+/// Transpose of [`generate_line_pattern`]: a single bright row instead of
+/// a bright column, for probing the vertical-axis kernel.
+pub fn generate_line_pattern_vertical() -> ImgVec<u8> {
+    transpose(&generate_line_pattern().as_ref())
+}
+
+/// Generate a sparse 2D grid of isolated bright dots ([`impulse_grid_centers`]),
+/// spaced [`IMPULSE_GRID_SPACING`] pixels apart on both axes so a typical
+/// filter's support doesn't overlap between neighboring dots after a
+/// same-size resize. Each dot's output neighborhood then directly reveals
+/// the resizer's 2D point-spread function, for detecting kernels that
+/// aren't a product of independent horizontal and vertical passes.
+pub fn generate_impulse_grid_pattern() -> ImgVec<u8> {
+    let mut pixels = vec![DARK; IMPULSE_GRID_SIZE * IMPULSE_GRID_SIZE];
+
+    for &(x, y) in &impulse_grid_centers() {
+        pixels[y * IMPULSE_GRID_SIZE + x] = BRIGHT;
+    }
+
+    ImgVec::new(pixels, IMPULSE_GRID_SIZE, IMPULSE_GRID_SIZE)
+}
+
+/// Source-image coordinates of each dot placed by [`generate_impulse_grid_pattern`].
+pub fn impulse_grid_centers() -> Vec<(usize, usize)> {
+    let mut centers = Vec::new();
+    let mut y = IMPULSE_GRID_MARGIN;
+
+    while y < IMPULSE_GRID_SIZE - IMPULSE_GRID_MARGIN {
+        let mut x = IMPULSE_GRID_MARGIN;
+        while x < IMPULSE_GRID_SIZE - IMPULSE_GRID_MARGIN {
+            centers.push((x, y));
+            x += IMPULSE_GRID_SPACING;
+        }
+        y += IMPULSE_GRID_SPACING;
+    }
+
+    centers
+}
+
+/// Generate a single isolated bright pixel on a DARK field, centered at
+/// ([`IMPULSE_CENTER`], [`IMPULSE_CENTER`]). A same-size resize of this
+/// directly reveals the resizer's full 2D point-spread function around the
+/// impulse, for testing whether it's a separable tensor-product kernel or
+/// a radially symmetric one (see [`crate::radial::detect`]).
+pub fn generate_impulse_pattern() -> ImgVec<u8> {
+    let mut pixels = vec![DARK; IMPULSE_SIZE * IMPULSE_SIZE];
+    pixels[IMPULSE_CENTER * IMPULSE_SIZE + IMPULSE_CENTER] = BRIGHT;
+    ImgVec::new(pixels, IMPULSE_SIZE, IMPULSE_SIZE)
+}
+
+fn column_pattern(col: usize) -> ImgVec<u8> {
     let mut pixels = vec![DARK; LINE_SRC_WIDTH * LINE_SRC_HEIGHT];
 
     for y in 0..LINE_SRC_HEIGHT {
-        pixels[y * LINE_SRC_WIDTH + 1] = BRIGHT;
+        pixels[y * LINE_SRC_WIDTH + col] = BRIGHT;
+    }
+
+    ImgVec::new(pixels, LINE_SRC_WIDTH, LINE_SRC_HEIGHT)
+}
+
+fn row_pattern(row: usize) -> ImgVec<u8> {
+    let mut pixels = vec![DARK; LINE_SRC_WIDTH * LINE_SRC_HEIGHT];
+
+    for x in 0..LINE_SRC_WIDTH {
+        pixels[row * LINE_SRC_WIDTH + x] = BRIGHT;
     }
 
     ImgVec::new(pixels, LINE_SRC_WIDTH, LINE_SRC_HEIGHT)
@@ -141,4 +276,109 @@ mod tests {
             assert_eq!(img.buf()[y * LINE_SRC_WIDTH + 2], DARK);
         }
     }
+
+    #[test]
+    fn edge_pattern_right_column_near_right_edge() {
+        let img = generate_edge_pattern_right();
+        let col = LINE_SRC_WIDTH - 2;
+        for y in 0..LINE_SRC_HEIGHT {
+            assert_eq!(img.buf()[y * LINE_SRC_WIDTH + col], BRIGHT);
+            assert_eq!(img.buf()[y * LINE_SRC_WIDTH + col - 1], DARK);
+            assert_eq!(img.buf()[y * LINE_SRC_WIDTH + col + 1], DARK);
+        }
+    }
+
+    #[test]
+    fn edge_pattern_top_row_near_top_edge() {
+        let img = generate_edge_pattern_top();
+        for x in 0..LINE_SRC_WIDTH {
+            assert_eq!(img.buf()[0 * LINE_SRC_WIDTH + x], DARK);
+            assert_eq!(img.buf()[1 * LINE_SRC_WIDTH + x], BRIGHT);
+            assert_eq!(img.buf()[2 * LINE_SRC_WIDTH + x], DARK);
+        }
+    }
+
+    #[test]
+    fn edge_pattern_bottom_row_near_bottom_edge() {
+        let img = generate_edge_pattern_bottom();
+        let row = LINE_SRC_HEIGHT - 2;
+        for x in 0..LINE_SRC_WIDTH {
+            assert_eq!(img.buf()[row * LINE_SRC_WIDTH + x], BRIGHT);
+            assert_eq!(img.buf()[(row - 1) * LINE_SRC_WIDTH + x], DARK);
+            assert_eq!(img.buf()[(row + 1) * LINE_SRC_WIDTH + x], DARK);
+        }
+    }
+
+    #[test]
+    fn dot_pattern_vertical_is_transposed() {
+        let horizontal = generate_dot_pattern();
+        let vertical = generate_dot_pattern_vertical();
+        assert_eq!(vertical.width(), horizontal.height());
+        assert_eq!(vertical.height(), horizontal.width());
+        assert_eq!(
+            vertical.buf()[DOT_HCENTER * vertical.stride() + DOT_VCENTER],
+            BRIGHT
+        );
+    }
+
+    #[test]
+    fn line_pattern_vertical_center_row() {
+        let img = generate_line_pattern_vertical();
+        assert_eq!(img.width(), LINE_SRC_WIDTH);
+        assert_eq!(img.height(), LINE_SRC_HEIGHT);
+        for y in 0..LINE_SRC_HEIGHT {
+            for x in 0..LINE_SRC_WIDTH {
+                let expected = if y == 7 { BRIGHT } else { DARK };
+                assert_eq!(
+                    img.buf()[y * LINE_SRC_WIDTH + x],
+                    expected,
+                    "mismatch at ({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn impulse_grid_pattern_has_isolated_dots() {
+        let img = generate_impulse_grid_pattern();
+        let centers = impulse_grid_centers();
+        assert!(centers.len() > 10);
+
+        let bright_count = img.buf().iter().filter(|&&v| v == BRIGHT).count();
+        assert_eq!(bright_count, centers.len());
+
+        for &(x, y) in &centers {
+            assert_eq!(img.buf()[y * IMPULSE_GRID_SIZE + x], BRIGHT);
+            // Neighbors should be dark: dots are isolated by IMPULSE_GRID_SPACING.
+            assert_eq!(img.buf()[y * IMPULSE_GRID_SIZE + x - 1], DARK);
+            assert_eq!(img.buf()[y * IMPULSE_GRID_SIZE + x + 1], DARK);
+        }
+    }
+
+    #[test]
+    fn impulse_pattern_has_single_bright_pixel() {
+        let img = generate_impulse_pattern();
+        assert_eq!(img.width(), IMPULSE_SIZE);
+        assert_eq!(img.height(), IMPULSE_SIZE);
+
+        let bright_count = img.buf().iter().filter(|&&v| v == BRIGHT).count();
+        assert_eq!(bright_count, 1);
+        assert_eq!(
+            img.buf()[IMPULSE_CENTER * IMPULSE_SIZE + IMPULSE_CENTER],
+            BRIGHT
+        );
+    }
+
+    #[test]
+    fn alpha_pattern_opaque_column_over_transparent_magenta() {
+        let img = generate_alpha_pattern();
+        let middle = LINE_SRC_WIDTH / 2;
+        for y in 0..LINE_SRC_HEIGHT {
+            let foreground = img.buf()[y * LINE_SRC_WIDTH + middle];
+            assert_eq!(foreground, RGBA8::new(255, 255, 255, 255));
+
+            let background = img.buf()[y * LINE_SRC_WIDTH + middle - 1];
+            assert_eq!(background, RGBA8::new(255, 0, 255, 0));
+        }
+    }
 }
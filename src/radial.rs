@@ -0,0 +1,234 @@
+//! Detect whether a resizer's full 2D point-spread function is
+//! product-separable (a tensor-product filter, matching the outer product
+//! of its own central row and column) or radially symmetric (a function of
+//! distance from the center, as in a cylindrical/EWA filter such as Jinc).
+
+use std::collections::BTreeMap;
+
+use crate::analyze;
+use crate::colorspace::Transfer;
+use crate::{check_dimensions, pattern, Error, ResizeFn};
+
+/// Bin width, in pixels, for averaging samples into the radial profile.
+const RADIAL_BIN_WIDTH: f64 = 1.0;
+
+/// Which model best explains a 2D impulse response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KernelShape {
+    /// Matches the outer product of its own central row and column: a
+    /// classic separable tensor-product filter.
+    Tensor,
+    /// Matches a radial profile (a function of distance from the center)
+    /// better than the tensor-product model: a cylindrical/EWA filter.
+    Cylindrical,
+}
+
+/// Result of probing a resizer's 2D impulse response for separability.
+#[derive(Debug, Clone)]
+pub struct TwoDResult {
+    pub shape: KernelShape,
+    /// Relative RMS residual of the response against the tensor-product
+    /// (outer product of central row/column) model.
+    pub tensor_residual: f64,
+    /// Relative RMS residual of the response against the fit radial
+    /// profile.
+    pub radial_residual: f64,
+    /// `(distance, weight)` samples of the fit radial profile, binned by
+    /// distance from the center and averaged, nearest-first.
+    pub radial_profile: Vec<(f64, f64)>,
+}
+
+/// Residual of `patch` against the outer product of its own central row and
+/// column, normalized by the center weight so the product reproduces it
+/// exactly there.
+fn tensor_residual(patch: &[Vec<f64>], center: usize) -> f64 {
+    let row = &patch[center];
+    let col: Vec<f64> = patch.iter().map(|r| r[center]).collect();
+    let center_weight = patch[center][center];
+    if center_weight.abs() < 1e-9 {
+        return 0.0;
+    }
+
+    let mut sq_error = 0.0;
+    let mut sq_total = 0.0;
+    for (y, patch_row) in patch.iter().enumerate() {
+        for (x, &v) in patch_row.iter().enumerate() {
+            let approx = row[x] * col[y] / center_weight;
+            sq_error += (v - approx).powi(2);
+            sq_total += v.powi(2);
+        }
+    }
+
+    if sq_total < 1e-12 {
+        0.0
+    } else {
+        (sq_error / sq_total).sqrt()
+    }
+}
+
+/// Fit a radial profile by averaging samples into distance bins, then
+/// report the relative RMS residual of `patch` against that profile
+/// (looked up by nearest bin) alongside the profile itself.
+fn radial_fit(patch: &[Vec<f64>], center: usize) -> (f64, Vec<(f64, f64)>) {
+    let mut bins: BTreeMap<u32, (f64, u32)> = BTreeMap::new();
+
+    for (y, patch_row) in patch.iter().enumerate() {
+        for (x, &v) in patch_row.iter().enumerate() {
+            let dx = x as f64 - center as f64;
+            let dy = y as f64 - center as f64;
+            let bin = ((dx * dx + dy * dy).sqrt() / RADIAL_BIN_WIDTH).round() as u32;
+            let entry = bins.entry(bin).or_insert((0.0, 0));
+            entry.0 += v;
+            entry.1 += 1;
+        }
+    }
+
+    let profile: Vec<(f64, f64)> = bins
+        .iter()
+        .map(|(&bin, &(sum, count))| (bin as f64 * RADIAL_BIN_WIDTH, sum / count as f64))
+        .collect();
+
+    let mut sq_error = 0.0;
+    let mut sq_total = 0.0;
+    for (y, patch_row) in patch.iter().enumerate() {
+        for (x, &v) in patch_row.iter().enumerate() {
+            let dx = x as f64 - center as f64;
+            let dy = y as f64 - center as f64;
+            let bin = ((dx * dx + dy * dy).sqrt() / RADIAL_BIN_WIDTH).round() as u32;
+            let (sum, count) = bins[&bin];
+            let approx = sum / count as f64;
+            sq_error += (v - approx).powi(2);
+            sq_total += v.powi(2);
+        }
+    }
+
+    let residual = if sq_total < 1e-12 {
+        0.0
+    } else {
+        (sq_error / sq_total).sqrt()
+    };
+
+    (residual, profile)
+}
+
+/// Probe a resizer's 2D impulse response for separability: a same-size
+/// resize of [`pattern::generate_impulse_pattern`] directly reveals the 2D
+/// kernel shape around the impulse.
+///
+/// Compares the response against the outer product of its own central row
+/// and column (tensor hypothesis) and against a radial profile fit from
+/// the response itself (cylindrical hypothesis), reporting whichever has
+/// the lower residual.
+pub fn detect(resize: &ResizeFn, transfer: Transfer) -> Result<TwoDResult, Error> {
+    let src = pattern::generate_impulse_pattern();
+    let size = src.width();
+    let resized = resize(src.as_ref(), size, size);
+    check_dimensions(&resized, size, size)?;
+
+    let patch = analyze::analyze_2d(&resized.as_ref(), transfer);
+    let center = pattern::IMPULSE_CENTER;
+
+    let tensor_residual = tensor_residual(&patch, center);
+    let (radial_residual, radial_profile) = radial_fit(&patch, center);
+
+    let shape = if tensor_residual <= radial_residual {
+        KernelShape::Tensor
+    } else {
+        KernelShape::Cylindrical
+    };
+
+    Ok(TwoDResult {
+        shape,
+        tensor_residual,
+        radial_residual,
+        radial_profile,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imgref::{ImgRef, ImgVec};
+
+    /// Nearest-neighbor resize, identical on both axes, for testing.
+    fn nn_resize(src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize) -> ImgVec<u8> {
+        let mut dst = vec![0u8; dst_w * dst_h];
+        for y in 0..dst_h {
+            let sy = ((y as f64 + 0.5) * src.height() as f64 / dst_h as f64 - 0.5)
+                .round()
+                .clamp(0.0, (src.height() - 1) as f64) as usize;
+            for x in 0..dst_w {
+                let sx = ((x as f64 + 0.5) * src.width() as f64 / dst_w as f64 - 0.5)
+                    .round()
+                    .clamp(0.0, (src.width() - 1) as f64) as usize;
+                dst[y * dst_w + x] = src.buf()[sy * src.stride() + sx];
+            }
+        }
+        ImgVec::new(dst, dst_w, dst_h)
+    }
+
+    #[test]
+    fn nn_resize_has_near_zero_residual_both_ways() {
+        // A single isolated impulse survives a same-size NN resize
+        // untouched, so both hypotheses fit it trivially well.
+        let result = detect(&nn_resize, Transfer::Linear).unwrap();
+        assert!(result.tensor_residual < 1e-9, "{}", result.tensor_residual);
+        assert!(result.radial_residual < 1e-9, "{}", result.radial_residual);
+    }
+
+    #[test]
+    fn tensor_residual_is_zero_for_outer_product_patch() {
+        let row = [1.0, 4.0, 1.0];
+        let patch: Vec<Vec<f64>> = row.iter().map(|&r| row.iter().map(|&c| r * c).collect()).collect();
+        let residual = tensor_residual(&patch, 1);
+        assert!(residual < 1e-9, "residual = {residual}");
+    }
+
+    #[test]
+    fn radial_fit_is_zero_for_radially_symmetric_patch() {
+        let size = 9;
+        let center = size / 2;
+        // Built as a function of the *same* rounded distance bin radial_fit
+        // itself groups by, rather than of raw (dx, dy), so every sample in
+        // a bin is exactly equal and the profile reproduces the patch with
+        // zero residual. A patch built from a smooth function of distance
+        // (e.g. 1.0 / (1.0 + dx*dx + dy*dy)) instead has distinct samples
+        // landing in the same unit-width bin at a slightly different exact
+        // distance, which averages to a nonzero residual even though the
+        // patch is radially symmetric.
+        let patch: Vec<Vec<f64>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let dx = x as f64 - center as f64;
+                        let dy = y as f64 - center as f64;
+                        let bin = ((dx * dx + dy * dy).sqrt() / RADIAL_BIN_WIDTH).round();
+                        1.0 / (1.0 + bin)
+                    })
+                    .collect()
+            })
+            .collect();
+        let (residual, profile) = radial_fit(&patch, center);
+        assert!(residual < 1e-9, "residual = {residual}");
+        assert!(!profile.is_empty());
+    }
+
+    #[test]
+    fn tensor_residual_is_large_for_radially_symmetric_patch() {
+        let size = 9;
+        let center = size / 2;
+        let patch: Vec<Vec<f64>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let dx = x as f64 - center as f64;
+                        let dy = y as f64 - center as f64;
+                        1.0 / (1.0 + dx * dx + dy * dy)
+                    })
+                    .collect()
+            })
+            .collect();
+        let residual = tensor_residual(&patch, center);
+        assert!(residual > 0.05, "residual = {residual}");
+    }
+}
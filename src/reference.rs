@@ -1,6 +1,8 @@
 use imgref::{ImgRef, ImgVec};
 
+use crate::colorspace::Transfer;
 use crate::filters::KnownFilter;
+use crate::ResizeFn;
 
 /// A single weight entry: which source pixel contributes and by how much.
 #[derive(Debug, Clone)]
@@ -73,17 +75,82 @@ pub fn compute_weights(filter: KnownFilter, src_size: usize, dst_size: usize) ->
     result
 }
 
+/// A pixel sample type the weight engine can blend.
+///
+/// Implemented for `u8`, `u16`, and `f32` so resizers working at higher
+/// bit depth (or in floating point) can be analyzed without the
+/// quantization noise an 8-bit round trip would add to the detected
+/// support and RMS scores.
+pub trait Sample: Copy + Default {
+    /// Convert to an `f64` for weighted accumulation.
+    fn to_f64(self) -> f64;
+    /// Convert back from the accumulated `f64`, clamping to the type's range.
+    fn from_f64(v: f64) -> Self;
+}
+
+impl Sample for u8 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(v: f64) -> Self {
+        v.round().clamp(0.0, 255.0) as u8
+    }
+}
+
+impl Sample for u16 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(v: f64) -> Self {
+        v.round().clamp(0.0, 65535.0) as u16
+    }
+}
+
+impl Sample for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+}
+
+/// Apply 1D weights to a row of source samples, producing one output row.
+fn apply_weights_row_generic<T: Sample>(weights: &[PixelWeights], src_row: &[T]) -> Vec<T> {
+    weights
+        .iter()
+        .map(|pw| {
+            let val: f64 = pw
+                .entries
+                .iter()
+                .map(|e| src_row[e.src_pixel].to_f64() * e.weight)
+                .sum();
+            T::from_f64(val)
+        })
+        .collect()
+}
+
 /// Apply 1D weights to a row of source pixels, producing one output row.
 fn apply_weights_row(weights: &[PixelWeights], src_row: &[u8]) -> Vec<u8> {
+    apply_weights_row_generic(weights, src_row)
+}
+
+/// Apply 1D weights to a row of source pixels through `transfer`: decode
+/// each sample, blend in linear light, then re-encode.
+fn apply_weights_row_transfer(
+    weights: &[PixelWeights],
+    src_row: &[u8],
+    transfer: &Transfer,
+) -> Vec<u8> {
     weights
         .iter()
         .map(|pw| {
-            let val: f64 = pw
+            let val_lin: f64 = pw
                 .entries
                 .iter()
-                .map(|e| src_row[e.src_pixel] as f64 * e.weight)
+                .map(|e| transfer.decode(src_row[e.src_pixel] as f64 / 255.0) * e.weight)
                 .sum();
-            val.round().clamp(0.0, 255.0) as u8
+            transfer.encode_u8(val_lin)
         })
         .collect()
 }
@@ -91,20 +158,94 @@ fn apply_weights_row(weights: &[PixelWeights], src_row: &[u8]) -> Vec<u8> {
 /// Generate the mathematically perfect resize output for a given filter.
 ///
 /// Uses separable 2D resize: horizontal pass then vertical pass.
-/// Edge handling is clamp (repeat edge pixel).
+/// Edge handling is clamp (repeat edge pixel). Blending happens directly
+/// on the 8-bit sRGB-encoded samples; use [`perfect_resize_srgb`] to blend
+/// in linear light instead.
 pub fn perfect_resize(
     src: ImgRef<'_, u8>,
     dst_width: usize,
     dst_height: usize,
     filter: KnownFilter,
+) -> ImgVec<u8> {
+    perfect_resize_impl(src, dst_width, dst_height, filter, &Transfer::Linear)
+}
+
+/// Generate the mathematically perfect resize output for a given filter,
+/// blending in linear light.
+///
+/// Each source sample is decoded with the sRGB EOTF before the weighted
+/// sum, and the result is re-encoded with the inverse OETF. Both the
+/// horizontal and vertical passes operate in the same (linear) domain,
+/// so this is the correct reference to compare against a resizer that
+/// gamma-corrects before resampling (`AnalysisConfig.transfer = Transfer::Srgb`).
+pub fn perfect_resize_srgb(
+    src: ImgRef<'_, u8>,
+    dst_width: usize,
+    dst_height: usize,
+    filter: KnownFilter,
+) -> ImgVec<u8> {
+    perfect_resize_impl(src, dst_width, dst_height, filter, &Transfer::Srgb)
+}
+
+/// Generate the mathematically perfect resize output for a given filter,
+/// blending through an arbitrary [`Transfer`] instead of just sRGB. Use
+/// this to build a reference for a resizer that linearizes through a
+/// plain gamma, Rec.709, or other non-sRGB curve
+/// (`AnalysisConfig.transfer` set to match).
+pub fn perfect_resize_with_transfer(
+    src: ImgRef<'_, u8>,
+    dst_width: usize,
+    dst_height: usize,
+    filter: KnownFilter,
+    transfer: &Transfer,
+) -> ImgVec<u8> {
+    perfect_resize_impl(src, dst_width, dst_height, filter, transfer)
+}
+
+/// Build a [`crate::ResizeFn`] closure that resizes with `filter` via
+/// [`perfect_resize`]. Exercises `analyze` end-to-end without wiring up an
+/// external resizer: feed the adapter into `analyze` and the reconstructed
+/// curve's top score should come back as the same `filter`.
+pub fn resize_fn(filter: KnownFilter) -> Box<ResizeFn> {
+    Box::new(move |src, dst_width, dst_height| perfect_resize(src, dst_width, dst_height, filter))
+}
+
+/// Like [`resize_fn`], but gamma-correct: resizes via [`perfect_resize_srgb`]
+/// so the adapter matches a resizer that linearizes before blending.
+pub fn resize_fn_srgb(filter: KnownFilter) -> Box<ResizeFn> {
+    Box::new(move |src, dst_width, dst_height| perfect_resize_srgb(src, dst_width, dst_height, filter))
+}
+
+/// Like [`resize_fn`], but resizes via [`perfect_resize_with_transfer`] so
+/// the adapter matches a resizer that linearizes through an arbitrary
+/// [`Transfer`] (a plain gamma, Rec.709, etc.), not just sRGB.
+pub fn resize_fn_with_transfer(filter: KnownFilter, transfer: Transfer) -> Box<ResizeFn> {
+    Box::new(move |src, dst_width, dst_height| {
+        perfect_resize_with_transfer(src, dst_width, dst_height, filter, &transfer)
+    })
+}
+
+fn perfect_resize_impl(
+    src: ImgRef<'_, u8>,
+    dst_width: usize,
+    dst_height: usize,
+    filter: KnownFilter,
+    transfer: &Transfer,
 ) -> ImgVec<u8> {
     let h_weights = compute_weights(filter, src.width(), dst_width);
+    let apply_row = |weights: &[PixelWeights], row: &[u8]| -> Vec<u8> {
+        if *transfer == Transfer::Linear {
+            apply_weights_row(weights, row)
+        } else {
+            apply_weights_row_transfer(weights, row, transfer)
+        }
+    };
 
     // Horizontal pass: resize each row.
     let mut temp = vec![0u8; dst_width * src.height()];
     for y in 0..src.height() {
         let src_row = &src.buf()[y * src.stride()..][..src.width()];
-        let dst_row = apply_weights_row(&h_weights, src_row);
+        let dst_row = apply_row(&h_weights, src_row);
         temp[y * dst_width..][..dst_width].copy_from_slice(&dst_row);
     }
 
@@ -119,20 +260,171 @@ pub fn perfect_resize(
     for x in 0..dst_width {
         // Extract the column from temp.
         let col: Vec<u8> = (0..src.height()).map(|y| temp[y * dst_width + x]).collect();
+        let dst_col = apply_row(&v_weights, &col);
 
-        for (y, pw) in v_weights.iter().enumerate() {
-            let val: f64 = pw
-                .entries
-                .iter()
-                .map(|e| col[e.src_pixel] as f64 * e.weight)
-                .sum();
-            result[y * dst_width + x] = val.round().clamp(0.0, 255.0) as u8;
+        for (y, &v) in dst_col.iter().enumerate() {
+            result[y * dst_width + x] = v;
+        }
+    }
+
+    ImgVec::new(result, dst_width, dst_height)
+}
+
+/// Generate the mathematically perfect resize output for a given filter,
+/// generic over the sample type (`u8`, `u16`, or `f32`).
+///
+/// Blends directly in the sample's own domain (no gamma correction);
+/// use this to validate high-bit-depth or floating-point resizers where
+/// 8-bit quantization would otherwise pollute the detected support and
+/// RMS scores. Edge handling is clamp (repeat edge pixel).
+pub fn perfect_resize_generic<T: Sample>(
+    src: ImgRef<'_, T>,
+    dst_width: usize,
+    dst_height: usize,
+    filter: KnownFilter,
+) -> ImgVec<T> {
+    let h_weights = compute_weights(filter, src.width(), dst_width);
+
+    // Horizontal pass: resize each row.
+    let mut temp = vec![T::default(); dst_width * src.height()];
+    for y in 0..src.height() {
+        let src_row = &src.buf()[y * src.stride()..][..src.width()];
+        let dst_row = apply_weights_row_generic(&h_weights, src_row);
+        temp[y * dst_width..][..dst_width].copy_from_slice(&dst_row);
+    }
+
+    // Vertical pass (only if height changes).
+    if dst_height == src.height() {
+        return ImgVec::new(temp, dst_width, dst_height);
+    }
+
+    let v_weights = compute_weights(filter, src.height(), dst_height);
+    let mut result = vec![T::default(); dst_width * dst_height];
+
+    for x in 0..dst_width {
+        // Extract the column from temp.
+        let col: Vec<T> = (0..src.height()).map(|y| temp[y * dst_width + x]).collect();
+        let dst_col = apply_weights_row_generic(&v_weights, &col);
+
+        for (y, &v) in dst_col.iter().enumerate() {
+            result[y * dst_width + x] = v;
         }
     }
 
     ImgVec::new(result, dst_width, dst_height)
 }
 
+/// A reusable resizer that precomputes its weight tables once for a fixed
+/// `(src_w, src_h, dst_w, dst_h, filter)` and reuses scratch buffers across
+/// calls, instead of recomputing [`compute_weights`] and reallocating on
+/// every call the way the free [`perfect_resize`] function does.
+///
+/// Behind the `rayon` feature, [`Resizer::resize_into`] parallelizes the
+/// horizontal pass over source rows and the vertical pass over output
+/// columns. The weight tables and the intermediate buffer are reused, but
+/// each call still allocates a fresh `Vec` per output row in the horizontal
+/// pass and per column in the vertical pass.
+pub struct Resizer {
+    src_width: usize,
+    src_height: usize,
+    dst_width: usize,
+    dst_height: usize,
+    h_weights: Vec<PixelWeights>,
+    v_weights: Vec<PixelWeights>,
+    scratch: std::cell::RefCell<Vec<u8>>,
+}
+
+impl Resizer {
+    /// Precompute the horizontal and vertical weight tables for this
+    /// `(src, dst, filter)` combination.
+    pub fn new(
+        src_width: usize,
+        src_height: usize,
+        dst_width: usize,
+        dst_height: usize,
+        filter: KnownFilter,
+    ) -> Self {
+        Self {
+            src_width,
+            src_height,
+            dst_width,
+            dst_height,
+            h_weights: compute_weights(filter, src_width, dst_width),
+            v_weights: compute_weights(filter, src_height, dst_height),
+            scratch: std::cell::RefCell::new(vec![0u8; dst_width * src_height]),
+        }
+    }
+
+    /// Resize `src` into `dst`, reusing the precomputed weight tables and
+    /// scratch buffer. `src` must match the dimensions this `Resizer` was
+    /// built for, and `dst` must already be sized `dst_width x dst_height`.
+    pub fn resize_into(&self, src: ImgRef<'_, u8>, dst: &mut ImgVec<u8>) {
+        assert_eq!(src.width(), self.src_width, "source width mismatch");
+        assert_eq!(src.height(), self.src_height, "source height mismatch");
+        assert_eq!(dst.width(), self.dst_width, "dest width mismatch");
+        assert_eq!(dst.height(), self.dst_height, "dest height mismatch");
+
+        let mut temp = self.scratch.borrow_mut();
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            temp.par_chunks_mut(self.dst_width)
+                .enumerate()
+                .for_each(|(y, dst_row)| {
+                    let src_row = &src.buf()[y * src.stride()..][..self.src_width];
+                    dst_row.copy_from_slice(&apply_weights_row(&self.h_weights, src_row));
+                });
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            for y in 0..self.src_height {
+                let src_row = &src.buf()[y * src.stride()..][..self.src_width];
+                let dst_row = apply_weights_row(&self.h_weights, src_row);
+                temp[y * self.dst_width..][..self.dst_width].copy_from_slice(&dst_row);
+            }
+        }
+
+        if self.dst_height == self.src_height {
+            dst.buf_mut().copy_from_slice(&temp);
+            return;
+        }
+
+        #[cfg(feature = "rayon")]
+        let columns: Vec<Vec<u8>> = {
+            use rayon::prelude::*;
+            // `temp` is a `RefMut`, whose borrow-tracking `Cell` isn't
+            // `Sync`; a plain shared slice is, so take one before handing
+            // the closure to rayon rather than capturing `temp` itself.
+            let temp_slice: &[u8] = &temp;
+            (0..self.dst_width)
+                .into_par_iter()
+                .map(|x| {
+                    let col: Vec<u8> = (0..self.src_height)
+                        .map(|y| temp_slice[y * self.dst_width + x])
+                        .collect();
+                    apply_weights_row(&self.v_weights, &col)
+                })
+                .collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let columns: Vec<Vec<u8>> = (0..self.dst_width)
+            .map(|x| {
+                let col: Vec<u8> =
+                    (0..self.src_height).map(|y| temp[y * self.dst_width + x]).collect();
+                apply_weights_row(&self.v_weights, &col)
+            })
+            .collect();
+
+        let dst_buf = dst.buf_mut();
+        for (x, col) in columns.iter().enumerate() {
+            for (y, &v) in col.iter().enumerate() {
+                dst_buf[y * self.dst_width + x] = v;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +480,146 @@ mod tests {
         let mid = dst.buf()[(15 / 2) * 555 + 555 / 2];
         assert!(mid > 200, "peak should be bright, got {mid}");
     }
+
+    #[test]
+    fn srgb_roundtrip_is_near_identity() {
+        for v in 0..=255u8 {
+            let back = Transfer::Srgb.encode_u8(Transfer::Srgb.decode(v as f64 / 255.0));
+            assert!((back as i32 - v as i32).abs() <= 1, "v={v} back={back}");
+        }
+    }
+
+    #[test]
+    fn perfect_resize_srgb_preserves_uniform() {
+        let src = ImgVec::new(vec![128u8; 15 * 15], 15, 15);
+        let dst = perfect_resize_srgb(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        for &v in dst.buf() {
+            assert!((v as i32 - 128).abs() <= 1, "uniform image not preserved: {v}");
+        }
+    }
+
+    #[test]
+    fn perfect_resize_generic_u16_preserves_uniform() {
+        let src = ImgVec::new(vec![40_000u16; 15 * 15], 15, 15);
+        let dst = perfect_resize_generic(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        for &v in dst.buf() {
+            assert_eq!(v, 40_000, "uniform u16 image not preserved");
+        }
+    }
+
+    #[test]
+    fn perfect_resize_generic_f32_preserves_uniform() {
+        let src = ImgVec::new(vec![0.5f32; 15 * 15], 15, 15);
+        let dst = perfect_resize_generic(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        for &v in dst.buf() {
+            assert!((v - 0.5).abs() < 1e-5, "uniform f32 image not preserved: {v}");
+        }
+    }
+
+    #[test]
+    fn perfect_resize_srgb_not_darker_at_peak() {
+        // The line pattern's bright column should not come out darker at
+        // its peak when blended in linear light versus raw sRGB bytes.
+        let src = pattern::generate_line_pattern();
+        let srgb_dst = perfect_resize_srgb(src.as_ref(), 555, 15, KnownFilter::Triangle);
+        let raw_dst = perfect_resize(src.as_ref(), 555, 15, KnownFilter::Triangle);
+        let mid = (15 / 2) * 555 + 555 / 2;
+        assert!(
+            srgb_dst.buf()[mid] >= raw_dst.buf()[mid],
+            "linear-light blend should not be darker at the peak"
+        );
+    }
+
+    #[test]
+    fn resizer_matches_perfect_resize() {
+        let src = pattern::generate_line_pattern();
+        let resizer = Resizer::new(
+            src.width(),
+            src.height(),
+            555,
+            15,
+            KnownFilter::Lanczos3,
+        );
+        let mut dst = ImgVec::new(vec![0u8; 555 * 15], 555, 15);
+        resizer.resize_into(src.as_ref(), &mut dst);
+
+        let expected = perfect_resize(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        assert_eq!(dst.buf(), expected.buf());
+    }
+
+    #[test]
+    fn resizer_reuse_across_calls() {
+        let resizer = Resizer::new(15, 15, 555, 15, KnownFilter::Mitchell);
+        let src_a = pattern::generate_line_pattern();
+        let src_b = pattern::generate_dot_pattern();
+        // Only used for dimension consistency; reuse the same resizer twice.
+        let mut dst_a = ImgVec::new(vec![0u8; 555 * 15], 555, 15);
+        resizer.resize_into(src_a.as_ref(), &mut dst_a);
+
+        let src_b_row = ImgVec::new(src_b.buf()[..15 * 15].to_vec(), 15, 15);
+        let mut dst_b = ImgVec::new(vec![0u8; 555 * 15], 555, 15);
+        resizer.resize_into(src_b_row.as_ref(), &mut dst_b);
+
+        assert_ne!(dst_a.buf(), dst_b.buf());
+    }
+
+    #[test]
+    fn resize_fn_matches_perfect_resize() {
+        let src = pattern::generate_line_pattern();
+        let callback = resize_fn(KnownFilter::Mitchell);
+        let via_adapter = callback(src.as_ref(), 555, 15);
+        let direct = perfect_resize(src.as_ref(), 555, 15, KnownFilter::Mitchell);
+        assert_eq!(via_adapter.buf(), direct.buf());
+    }
+
+    #[test]
+    fn resize_fn_srgb_matches_perfect_resize_srgb() {
+        let src = pattern::generate_line_pattern();
+        let callback = resize_fn_srgb(KnownFilter::Lanczos3);
+        let via_adapter = callback(src.as_ref(), 555, 15);
+        let direct = perfect_resize_srgb(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        assert_eq!(via_adapter.buf(), direct.buf());
+    }
+
+    #[test]
+    fn perfect_resize_with_transfer_matches_srgb_for_srgb_transfer() {
+        let src = pattern::generate_line_pattern();
+        let via_transfer = perfect_resize_with_transfer(
+            src.as_ref(),
+            555,
+            15,
+            KnownFilter::Lanczos3,
+            &Transfer::Srgb,
+        );
+        let direct = perfect_resize_srgb(src.as_ref(), 555, 15, KnownFilter::Lanczos3);
+        assert_eq!(via_transfer.buf(), direct.buf());
+    }
+
+    #[test]
+    fn perfect_resize_with_transfer_supports_plain_gamma() {
+        // A plain gamma-2.2 pipeline should not come out darker at the
+        // bright column's peak than a raw blend, the same as sRGB, since
+        // both linearize before blending.
+        let src = pattern::generate_line_pattern();
+        let gamma = Transfer::Gamma(2.2);
+        let gamma_dst =
+            perfect_resize_with_transfer(src.as_ref(), 555, 15, KnownFilter::Triangle, &gamma);
+        let raw_dst = perfect_resize(src.as_ref(), 555, 15, KnownFilter::Triangle);
+        let mid = (15 / 2) * 555 + 555 / 2;
+        assert!(
+            gamma_dst.buf()[mid] >= raw_dst.buf()[mid],
+            "linear-light blend should not be darker at the peak"
+        );
+    }
+
+    #[test]
+    fn resize_fn_with_transfer_matches_perfect_resize_with_transfer() {
+        let src = pattern::generate_line_pattern();
+        let transfer = Transfer::Gamma(2.2);
+        let callback = resize_fn_with_transfer(KnownFilter::Mitchell, transfer.clone());
+        let via_adapter = callback(src.as_ref(), 555, 15);
+        let direct =
+            perfect_resize_with_transfer(src.as_ref(), 555, 15, KnownFilter::Mitchell, &transfer);
+        assert_eq!(via_adapter.buf(), direct.buf());
+    }
 }
@@ -10,19 +10,33 @@ pub struct FilterScore {
     pub max_error: f64,
     pub detected_support: f64,
     pub expected_support: f64,
+    /// Horizontal shift (in source-pixel units) that best aligns the
+    /// reconstructed curve with this filter before scoring. A resizer that
+    /// samples off-center (a half-pixel convention mismatch) will show a
+    /// nonzero offset here even when the filter shape is otherwise correct.
+    pub phase_offset: f64,
+    /// Support-scale factor `s` such that `filter.evaluate(x/s)/s` best
+    /// fits the reconstructed curve. `Robidoux` and `RobidouxSharp` (and
+    /// similar "Sharp" variants used by other resizers) match the same base
+    /// kernel shape and differ only by this scalar stretch of the support;
+    /// `blur_factor` is how that distinction shows up here even though both
+    /// land on the same [`KnownFilter`]. 1.0 means no stretch.
+    pub blur_factor: f64,
 }
 
 impl std::fmt::Display for FilterScore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}: r={:.4} rms={:.4} max={:.4} support={:.1}/{:.1}",
+            "{}: r={:.4} rms={:.4} max={:.4} support={:.1}/{:.1} offset={:.3} blur={:.3}",
             self.filter,
             self.correlation,
             self.rms_error,
             self.max_error,
             self.detected_support,
-            self.expected_support
+            self.expected_support,
+            self.phase_offset,
+            self.blur_factor
         )
     }
 }
@@ -96,13 +110,115 @@ fn detect_support(points: &[(f64, f64)], threshold: f64) -> f64 {
         .fold(0.0_f64, f64::max)
 }
 
+/// Correlation between `filter` and `points` after shifting every x-offset
+/// by `-shift` and (for scatter data) re-binning.
+fn correlation_at_shift(points: &[(f64, f64)], filter: KnownFilter, shift: f64, is_scatter: bool) -> f64 {
+    let shifted: Vec<(f64, f64)> = points.iter().map(|&(x, y)| (x - shift, y)).collect();
+    let comparison = if is_scatter {
+        bin_scatter(&shifted, 0.02)
+    } else {
+        shifted
+    };
+
+    if comparison.is_empty() {
+        return 0.0;
+    }
+
+    let actual: Vec<f64> = comparison.iter().map(|p| p.1).collect();
+    let reference: Vec<f64> = comparison.iter().map(|p| filter.evaluate(p.0)).collect();
+    pearson(&actual, &reference)
+}
+
+/// Search for the horizontal shift in `[-1.0, 1.0]` (0.02-pixel steps) that
+/// maximizes correlation between the curve and `filter`, acting as a 1D
+/// cross-correlation alignment that removes a half-pixel (or other)
+/// sampling-convention offset before scoring.
+fn detect_phase_offset(curve: &FilterCurve, filter: KnownFilter) -> f64 {
+    let mut best_shift = 0.0_f64;
+    let mut best_corr = correlation_at_shift(&curve.points, filter, 0.0, curve.is_scatter);
+
+    let steps = (2.0_f64 / 0.02).round() as i32;
+    for i in 0..=steps {
+        let shift = -1.0 + i as f64 * 0.02;
+        let corr = correlation_at_shift(&curve.points, filter, shift, curve.is_scatter);
+        if corr > best_corr {
+            best_corr = corr;
+            best_shift = shift;
+        }
+    }
+
+    best_shift
+}
+
+/// Mean squared error between `points` and `filter`'s kernel stretched by a
+/// support-scale factor `s`, i.e. `filter.evaluate(x/s)/s`.
+fn blur_scale_error(points: &[(f64, f64)], filter: KnownFilter, s: f64) -> f64 {
+    points
+        .iter()
+        .map(|&(x, y)| {
+            let reference = filter.evaluate(x / s) / s;
+            (y - reference).powi(2)
+        })
+        .sum::<f64>()
+        / points.len().max(1) as f64
+}
+
+/// Search `[0.7, 1.3]` by golden-section for the support-scale factor `s`
+/// minimizing [`blur_scale_error`]. The error is unimodal in `s` near 1.0,
+/// so golden-section search (unlike [`detect_phase_offset`]'s brute step
+/// scan) converges to tight precision in a small, fixed number of evals.
+fn detect_blur_factor(points: &[(f64, f64)], filter: KnownFilter) -> f64 {
+    if points.is_empty() {
+        return 1.0;
+    }
+
+    const GOLDEN: f64 = 0.618_033_988_749_895;
+    let (mut lo, mut hi) = (0.7_f64, 1.3_f64);
+    let mut c = hi - GOLDEN * (hi - lo);
+    let mut d = lo + GOLDEN * (hi - lo);
+    let mut fc = blur_scale_error(points, filter, c);
+    let mut fd = blur_scale_error(points, filter, d);
+
+    for _ in 0..50 {
+        if hi - lo < 1e-4 {
+            break;
+        }
+        if fc < fd {
+            hi = d;
+            d = c;
+            fd = fc;
+            c = hi - GOLDEN * (hi - lo);
+            fc = blur_scale_error(points, filter, c);
+        } else {
+            lo = c;
+            c = d;
+            fc = fd;
+            d = lo + GOLDEN * (hi - lo);
+            fd = blur_scale_error(points, filter, d);
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
 /// Score a reconstructed curve against a single reference filter.
+///
+/// Before scoring, searches for and removes a small horizontal phase
+/// offset (see [`detect_phase_offset`]) so a correct-but-shifted resampler
+/// doesn't look like a different filter.
 pub fn score_against(curve: &FilterCurve, filter: KnownFilter) -> FilterScore {
+    let phase_offset = detect_phase_offset(curve, filter);
+    let corrected_points: Vec<(f64, f64)> = curve
+        .points
+        .iter()
+        .map(|&(x, y)| (x - phase_offset, y))
+        .collect();
+
     // For scatter data, bin first; for connected data, use directly.
     let comparison_points = if curve.is_scatter {
-        bin_scatter(&curve.points, 0.02)
+        bin_scatter(&corrected_points, 0.02)
     } else {
-        curve.points.clone()
+        corrected_points
     };
 
     if comparison_points.is_empty() {
@@ -113,6 +229,8 @@ pub fn score_against(curve: &FilterCurve, filter: KnownFilter) -> FilterScore {
             max_error: f64::INFINITY,
             detected_support: 0.0,
             expected_support: filter.support(),
+            phase_offset,
+            blur_factor: 1.0,
         };
     }
 
@@ -143,6 +261,7 @@ pub fn score_against(curve: &FilterCurve, filter: KnownFilter) -> FilterScore {
         .fold(0.0_f64, f64::max);
 
     let detected_support = detect_support(&comparison_points, 0.005);
+    let blur_factor = detect_blur_factor(&comparison_points, filter);
 
     FilterScore {
         filter,
@@ -151,6 +270,8 @@ pub fn score_against(curve: &FilterCurve, filter: KnownFilter) -> FilterScore {
         max_error,
         detected_support,
         expected_support: filter.support(),
+        phase_offset,
+        blur_factor,
     }
 }
 
@@ -170,6 +291,418 @@ pub fn score_against_all(curve: &FilterCurve) -> Vec<FilterScore> {
     scores
 }
 
+/// A parametric filter family whose continuous parameters can be fit to a
+/// reconstructed curve, for identifying resizers that don't match any
+/// fixed [`KnownFilter`] preset exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterFamily {
+    /// Generalized Mitchell-Netravali cubic, parameterized by `(B, C)`.
+    Cubic,
+    /// Windowed-sinc cardinal filter, parameterized by `(blur, support)`:
+    /// `k(x) = sinc(x/blur) * sinc(x/(blur*support))` for `|x| < blur*support`.
+    Sinc,
+    /// Gaussian, parameterized by `(sigma, amplitude)`:
+    /// `k(x) = amplitude * exp(-x^2 / (2*sigma^2))`.
+    Gaussian,
+}
+
+impl FilterFamily {
+    fn initial_params(&self) -> Vec<f64> {
+        match self {
+            Self::Cubic => vec![1.0 / 3.0, 1.0 / 3.0],
+            Self::Sinc => vec![1.0, 3.0],
+            Self::Gaussian => vec![0.5, 1.0],
+        }
+    }
+
+    fn initial_steps(&self) -> Vec<f64> {
+        match self {
+            Self::Cubic => vec![0.1, 0.1],
+            Self::Sinc => vec![0.1, 0.5],
+            Self::Gaussian => vec![0.05, 0.05],
+        }
+    }
+
+    fn evaluate(&self, x: f64, params: &[f64]) -> f64 {
+        match self {
+            Self::Cubic => KnownFilter::MitchellNetravali {
+                b: params[0],
+                c: params[1],
+            }
+            .evaluate(x),
+            Self::Sinc => {
+                let blur = params[0].max(1e-6);
+                let support = params[1].max(1e-6);
+                let ax = x.abs();
+                if ax < blur * support {
+                    sinc_kernel(x / blur) * sinc_kernel(x / (blur * support))
+                } else {
+                    0.0
+                }
+            }
+            Self::Gaussian => {
+                let sigma = params[0].max(1e-6);
+                let amplitude = params[1];
+                amplitude * (-(x * x) / (2.0 * sigma * sigma)).exp()
+            }
+        }
+    }
+}
+
+fn sinc_kernel(x: f64) -> f64 {
+    if x.abs() < 1e-10 {
+        1.0
+    } else {
+        let px = std::f64::consts::PI * x;
+        px.sin() / px
+    }
+}
+
+/// Result of fitting a [`FilterFamily`] to a reconstructed curve.
+#[derive(Debug, Clone)]
+pub struct FitResult {
+    pub family: FilterFamily,
+    /// Fitted parameters, in the order documented on the matched `FilterFamily` variant.
+    pub params: Vec<f64>,
+    pub rms: f64,
+    pub correlation: f64,
+}
+
+/// Minimize `objective` over `params` with a coordinate (diamond) pattern search:
+/// at each iteration, try a step of the current size along each axis in each
+/// direction, move to the best improving neighbor, and halve all steps once
+/// no neighbor improves. Stops when the largest step drops below `tol` or
+/// `max_iters` is reached.
+fn pattern_search(
+    mut params: Vec<f64>,
+    mut steps: Vec<f64>,
+    tol: f64,
+    max_iters: u32,
+    objective: impl Fn(&[f64]) -> f64,
+) -> (Vec<f64>, f64) {
+    let mut best = objective(&params);
+
+    for _ in 0..max_iters {
+        if steps.iter().cloned().fold(0.0_f64, f64::max) < tol {
+            break;
+        }
+
+        let mut best_candidate: Option<Vec<f64>> = None;
+        for i in 0..params.len() {
+            for &dir in &[1.0, -1.0] {
+                let mut candidate = params.clone();
+                candidate[i] += dir * steps[i];
+                let val = objective(&candidate);
+                if val < best {
+                    best = val;
+                    best_candidate = Some(candidate);
+                }
+            }
+        }
+
+        match best_candidate {
+            Some(candidate) => params = candidate,
+            None => {
+                for s in &mut steps {
+                    *s *= 0.5;
+                }
+            }
+        }
+    }
+
+    (params, best)
+}
+
+/// Result of fitting a continuous Mitchell-Netravali cubic to a reconstructed curve.
+#[derive(Debug, Clone, Copy)]
+pub struct CubicFit {
+    pub b: f64,
+    pub c: f64,
+    /// RMS residual of the fitted cubic against the sampled curve.
+    pub rms: f64,
+    /// The nearest named cubic preset, if `(b, c)` falls within
+    /// [`CUBIC_SNAP_TOLERANCE`] of it in Euclidean `(B, C)` distance.
+    pub snapped: Option<KnownFilter>,
+}
+
+/// Maximum Euclidean `(B, C)` distance for [`CubicFit::snapped`] to report a
+/// named preset instead of leaving the fit as a raw, un-snapped cubic.
+const CUBIC_SNAP_TOLERANCE: f64 = 0.05;
+
+/// Named `(B, C)` presets eligible for [`CubicFit::snapped`].
+fn snap_cubic(b: f64, c: f64) -> Option<KnownFilter> {
+    let candidates = [
+        (1.0, 0.0, KnownFilter::BSpline),
+        (0.0, 0.5, KnownFilter::CatmullRom),
+        (1.0 / 3.0, 1.0 / 3.0, KnownFilter::Mitchell),
+        (0.0, 0.0, KnownFilter::Hermite),
+    ];
+    candidates
+        .into_iter()
+        .map(|(cb, cc, filter)| (((b - cb).powi(2) + (c - cc).powi(2)).sqrt(), filter))
+        .filter(|&(dist, _)| dist <= CUBIC_SNAP_TOLERANCE)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, filter)| filter)
+}
+
+/// The (g0, gB, gC) basis values such that the cubic kernel at `|x| = t`
+/// equals `g0 + B*gB + C*gC` (the Keys/Mitchell-Netravali family is affine
+/// in `B` and `C`).
+fn cubic_basis(t: f64) -> (f64, f64, f64) {
+    if t < 1.0 {
+        let g0 = (12.0 * t.powi(3) - 18.0 * t.powi(2) + 6.0) / 6.0;
+        let gb = (-9.0 * t.powi(3) + 12.0 * t.powi(2) - 2.0) / 6.0;
+        let gc = (-6.0 * t.powi(3) + 6.0 * t.powi(2)) / 6.0;
+        (g0, gb, gc)
+    } else if t < 2.0 {
+        let gb = (-t.powi(3) + 6.0 * t.powi(2) - 12.0 * t + 8.0) / 6.0;
+        let gc = (-6.0 * t.powi(3) + 30.0 * t.powi(2) - 48.0 * t + 24.0) / 6.0;
+        (0.0, gb, gc)
+    } else {
+        (0.0, 0.0, 0.0)
+    }
+}
+
+/// Fit the continuous Mitchell-Netravali `(B, C)` that best matches a
+/// reconstructed curve, by linear least squares.
+///
+/// The cubic kernel is affine in `(B, C)` at every sampled offset, so
+/// unlike [`fit_parametric`]'s iterative search, this solves the exact
+/// 2x2 normal equations for the optimal parameters directly. Points
+/// outside the cubic family's support (`|x| >= 2`) are excluded.
+pub fn fit_cubic(curve: &FilterCurve) -> CubicFit {
+    let points = if curve.is_scatter {
+        bin_scatter(&curve.points, 0.02)
+    } else {
+        curve.points.clone()
+    };
+
+    let mut a11 = 0.0;
+    let mut a12 = 0.0;
+    let mut a22 = 0.0;
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    let mut fit_points: Vec<(f64, f64)> = Vec::new();
+
+    for &(x, y) in &points {
+        let t = x.abs();
+        if t >= 2.0 {
+            continue;
+        }
+        let (g0, gb, gc) = cubic_basis(t);
+        let r = y - g0;
+        a11 += gb * gb;
+        a12 += gb * gc;
+        a22 += gc * gc;
+        b1 += gb * r;
+        b2 += gc * r;
+        fit_points.push((x, y));
+    }
+
+    if fit_points.is_empty() {
+        return CubicFit {
+            b: 0.0,
+            c: 0.0,
+            rms: f64::INFINITY,
+            snapped: None,
+        };
+    }
+
+    let det = a11 * a22 - a12 * a12;
+    let (b, c) = if det.abs() > 1e-12 {
+        ((b1 * a22 - a12 * b2) / det, (a11 * b2 - a12 * b1) / det)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let rms = (fit_points
+        .iter()
+        .map(|&(x, y)| (KnownFilter::MitchellNetravali { b, c }.evaluate(x) - y).powi(2))
+        .sum::<f64>()
+        / fit_points.len() as f64)
+        .sqrt();
+
+    CubicFit {
+        b,
+        c,
+        rms,
+        snapped: snap_cubic(b, c),
+    }
+}
+
+/// Fit a parametric filter family to a reconstructed curve by minimizing RMS
+/// against the binned curve with a pattern search, starting from the
+/// family's canonical parameters.
+pub fn fit_parametric(curve: &FilterCurve, family: FilterFamily) -> FitResult {
+    let bins = if curve.is_scatter {
+        bin_scatter(&curve.points, 0.02)
+    } else {
+        curve.points.clone()
+    };
+
+    if bins.is_empty() {
+        return FitResult {
+            family,
+            params: family.initial_params(),
+            rms: f64::INFINITY,
+            correlation: 0.0,
+        };
+    }
+
+    let objective = |params: &[f64]| -> f64 {
+        let se: f64 = bins
+            .iter()
+            .map(|&(x, y)| (family.evaluate(x, params) - y).powi(2))
+            .sum();
+        (se / bins.len() as f64).sqrt()
+    };
+
+    let (params, rms) = pattern_search(
+        family.initial_params(),
+        family.initial_steps(),
+        1e-4,
+        200,
+        objective,
+    );
+
+    let actual: Vec<f64> = bins.iter().map(|p| p.1).collect();
+    let reference: Vec<f64> = bins.iter().map(|p| family.evaluate(p.0, &params)).collect();
+    let correlation = pearson(&actual, &reference);
+
+    FitResult {
+        family,
+        params,
+        rms,
+        correlation,
+    }
+}
+
+/// Fit a Gaussian `A * exp(-x^2 / (2*sigma^2))` to a reconstructed curve.
+///
+/// Unlike [`fit_parametric`]'s iterative search, the Gaussian is linearized
+/// by taking `ln(w)` of each positive-weight sample: `ln(w) = ln(A) -
+/// x^2/(2*sigma^2)` is then a straight line in `x^2`, so ordinary least
+/// squares recovers `sigma` and `A` directly from its slope and intercept.
+fn fit_gaussian(curve: &FilterCurve) -> FitResult {
+    let bins = if curve.is_scatter {
+        bin_scatter(&curve.points, 0.02)
+    } else {
+        curve.points.clone()
+    };
+
+    let samples: Vec<(f64, f64)> = bins
+        .iter()
+        .filter(|&&(_, y)| y > 1e-6)
+        .map(|&(x, y)| (x * x, y.ln()))
+        .collect();
+
+    let params = if samples.len() < 2 {
+        FilterFamily::Gaussian.initial_params()
+    } else {
+        let n = samples.len() as f64;
+        let sum_x: f64 = samples.iter().map(|&(x, _)| x).sum();
+        let sum_y: f64 = samples.iter().map(|&(_, y)| y).sum();
+        let sum_xx: f64 = samples.iter().map(|&(x, _)| x * x).sum();
+        let sum_xy: f64 = samples.iter().map(|&(x, y)| x * y).sum();
+
+        let denom = n * sum_xx - sum_x * sum_x;
+        let (slope, intercept) = if denom.abs() > 1e-12 {
+            (
+                (n * sum_xy - sum_x * sum_y) / denom,
+                (sum_xx * sum_y - sum_x * sum_xy) / denom,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        let sigma = if slope < 0.0 {
+            (-1.0 / (2.0 * slope)).sqrt()
+        } else {
+            f64::INFINITY
+        };
+        vec![sigma, intercept.exp()]
+    };
+
+    let actual: Vec<f64> = bins.iter().map(|p| p.1).collect();
+    let reference: Vec<f64> = bins
+        .iter()
+        .map(|p| FilterFamily::Gaussian.evaluate(p.0, &params))
+        .collect();
+    let rms = if actual.is_empty() {
+        f64::INFINITY
+    } else {
+        (actual
+            .iter()
+            .zip(&reference)
+            .map(|(a, r)| (a - r).powi(2))
+            .sum::<f64>()
+            / actual.len() as f64)
+            .sqrt()
+    };
+
+    FitResult {
+        family: FilterFamily::Gaussian,
+        params,
+        rms,
+        correlation: pearson(&actual, &reference),
+    }
+}
+
+/// Wrap [`fit_cubic`]'s closed-form `(B, C)` fit as a [`FitResult`], for
+/// comparison against the other parametric families in [`FitReport`].
+fn fit_cubic_as_result(curve: &FilterCurve) -> FitResult {
+    let cubic = fit_cubic(curve);
+    let bins = if curve.is_scatter {
+        bin_scatter(&curve.points, 0.02)
+    } else {
+        curve.points.clone()
+    };
+
+    let actual: Vec<f64> = bins.iter().map(|p| p.1).collect();
+    let reference: Vec<f64> = bins
+        .iter()
+        .map(|&(x, _)| KnownFilter::MitchellNetravali { b: cubic.b, c: cubic.c }.evaluate(x))
+        .collect();
+
+    FitResult {
+        family: FilterFamily::Cubic,
+        params: vec![cubic.b, cubic.c],
+        rms: cubic.rms,
+        correlation: pearson(&actual, &reference),
+    }
+}
+
+/// Result of [`FilterCurve::fit_parametric`]: the best-matching parametric
+/// family, alongside every family attempted, so callers can see how close
+/// the runners-up came instead of only getting a single verdict.
+#[derive(Debug, Clone)]
+pub struct FitReport {
+    /// The attempt with the lowest RMS residual.
+    pub best: FitResult,
+    /// Every family tried, in the order attempted.
+    pub attempts: Vec<FitResult>,
+}
+
+impl FilterCurve {
+    /// Fit the cubic, windowed-sinc, and Gaussian families to this curve
+    /// and report whichever matches best, so a resizer whose kernel isn't
+    /// in the [`KnownFilter`] preset table can still be identified by its
+    /// continuous shape rather than only scored against fixed presets.
+    pub fn fit_parametric(&self) -> FitReport {
+        let attempts = vec![
+            fit_cubic_as_result(self),
+            fit_parametric(self, FilterFamily::Sinc),
+            fit_gaussian(self),
+        ];
+        let best = attempts
+            .iter()
+            .min_by(|a, b| a.rms.total_cmp(&b.rms))
+            .cloned()
+            .expect("at least one family attempted");
+        FitReport { best, attempts }
+    }
+}
+
 /// Compute SSIM between two equal-sized grayscale images.
 /// Uses 8x8 block-based comparison with standard SSIM constants.
 pub fn ssim(a: &[u8], b: &[u8], width: usize, height: usize) -> f64 {
@@ -250,6 +783,196 @@ pub fn ssim(a: &[u8], b: &[u8], width: usize, height: usize) -> f64 {
     total_ssim / count as f64
 }
 
+/// Average luminance and structure/contrast comparison factors between two
+/// equal-sized grayscale images, block-averaged the same way as [`ssim`].
+/// Splitting the two out lets callers (e.g. [`ms_ssim`]) weight
+/// low-frequency luminance shifts separately from structural differences.
+fn ssim_components(a: &[u8], b: &[u8], width: usize, height: usize) -> (f64, f64) {
+    const K1: f64 = 0.01;
+    const K2: f64 = 0.03;
+    const L: f64 = 255.0;
+    let c1 = (K1 * L) * (K1 * L);
+    let c2 = (K2 * L) * (K2 * L);
+    const BLOCK: usize = 8;
+
+    assert_eq!(a.len(), width * height);
+    assert_eq!(b.len(), width * height);
+
+    if width < BLOCK || height < BLOCK {
+        let mean_a: f64 = a.iter().map(|&v| v as f64).sum::<f64>() / a.len() as f64;
+        let mean_b: f64 = b.iter().map(|&v| v as f64).sum::<f64>() / b.len() as f64;
+        let var_a: f64 =
+            a.iter().map(|&v| (v as f64 - mean_a).powi(2)).sum::<f64>() / a.len() as f64;
+        let var_b: f64 =
+            b.iter().map(|&v| (v as f64 - mean_b).powi(2)).sum::<f64>() / b.len() as f64;
+        let cov: f64 = a
+            .iter()
+            .zip(b.iter())
+            .map(|(&va, &vb)| (va as f64 - mean_a) * (vb as f64 - mean_b))
+            .sum::<f64>()
+            / a.len() as f64;
+
+        let luminance = (2.0 * mean_a * mean_b + c1) / (mean_a.powi(2) + mean_b.powi(2) + c1);
+        let structure = (2.0 * cov + c2) / (var_a + var_b + c2);
+        return (luminance, structure);
+    }
+
+    let blocks_x = width / BLOCK;
+    let blocks_y = height / BLOCK;
+    let mut total_luminance = 0.0;
+    let mut total_structure = 0.0;
+    let mut count = 0;
+
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            let mut sum_a = 0.0_f64;
+            let mut sum_b = 0.0_f64;
+            let mut sum_aa = 0.0_f64;
+            let mut sum_bb = 0.0_f64;
+            let mut sum_ab = 0.0_f64;
+            let n = (BLOCK * BLOCK) as f64;
+
+            for dy in 0..BLOCK {
+                for dx in 0..BLOCK {
+                    let y = by * BLOCK + dy;
+                    let x = bx * BLOCK + dx;
+                    let va = a[y * width + x] as f64;
+                    let vb = b[y * width + x] as f64;
+                    sum_a += va;
+                    sum_b += vb;
+                    sum_aa += va * va;
+                    sum_bb += vb * vb;
+                    sum_ab += va * vb;
+                }
+            }
+
+            let mean_a = sum_a / n;
+            let mean_b = sum_b / n;
+            let var_a = sum_aa / n - mean_a * mean_a;
+            let var_b = sum_bb / n - mean_b * mean_b;
+            let cov = sum_ab / n - mean_a * mean_b;
+
+            total_luminance += (2.0 * mean_a * mean_b + c1) / (mean_a.powi(2) + mean_b.powi(2) + c1);
+            total_structure += (2.0 * cov + c2) / (var_a + var_b + c2);
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        return (1.0, 1.0);
+    }
+    (total_luminance / count as f64, total_structure / count as f64)
+}
+
+/// Per-scale luminance/structure comparison from a [`ms_ssim`] evaluation.
+#[derive(Debug, Clone)]
+pub struct ScaleComponent {
+    /// Box-downsampling factor relative to the original image (1, 2, 4, ...).
+    pub downsample: u32,
+    pub luminance: f64,
+    pub structure: f64,
+    pub weight: f64,
+}
+
+/// Multi-scale perceptual comparison report, combining luminance and
+/// structure/contrast factors across several resolutions.
+#[derive(Debug, Clone)]
+pub struct PerceptualScore {
+    /// Weighted combination of per-scale SSIM across all evaluated scales.
+    pub ms_ssim: f64,
+    pub scales: Vec<ScaleComponent>,
+}
+
+/// Box-downsample a grayscale image by 2x in each dimension.
+fn box_downsample_2x(img: &[u8], width: usize, height: usize) -> (Vec<u8>, usize, usize) {
+    let nw = width / 2;
+    let nh = height / 2;
+    let mut out = vec![0u8; nw * nh];
+    for y in 0..nh {
+        for x in 0..nw {
+            let sum = img[(2 * y) * width + 2 * x] as u32
+                + img[(2 * y) * width + 2 * x + 1] as u32
+                + img[(2 * y + 1) * width + 2 * x] as u32
+                + img[(2 * y + 1) * width + 2 * x + 1] as u32;
+            out[y * nw + x] = (sum / 4) as u8;
+        }
+    }
+    (out, nw, nh)
+}
+
+/// Multi-scale SSIM (Wang, Simoncelli & Bovik 2003): evaluate SSIM at the
+/// native resolution, then repeatedly 2x2-box-downsample (a simple
+/// low-pass filter) both images and recompute, stopping once a dimension
+/// would fall below the 8px block size used by [`ssim`] — so images as
+/// short as 15 rows still get at least one scale rather than failing.
+/// Combined as the standard weighted *product* across scales,
+/// `Π cs_j^beta_j * l_M^beta_M`: the contrast/structure term (`structure`,
+/// from [`ssim_components`]) contributes at every scale, while luminance
+/// only contributes at the coarsest scale `M` actually reached, since
+/// luminance differences are already captured there. Weights are the
+/// standard normalized exponents (0.0448, 0.2856, 0.3001, 0.2363, 0.1333),
+/// re-normalized over however many scales actually ran.
+///
+/// This catches both fine ringing (visible at native resolution) and
+/// low-frequency shifts (visible only after downsampling) that a
+/// single-scale [`ssim`] call misses.
+pub fn ms_ssim(a: &[u8], b: &[u8], width: usize, height: usize) -> PerceptualScore {
+    const WEIGHTS: [f64; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+    const BLOCK: usize = 8;
+
+    let mut cur_a = a.to_vec();
+    let mut cur_b = b.to_vec();
+    let mut w = width;
+    let mut h = height;
+    let mut downsample = 1;
+
+    let mut scales = Vec::new();
+
+    for &weight in &WEIGHTS {
+        let (luminance, structure) = ssim_components(&cur_a, &cur_b, w, h);
+        scales.push(ScaleComponent {
+            downsample,
+            luminance,
+            structure,
+            weight,
+        });
+
+        if w / 2 < BLOCK || h / 2 < BLOCK {
+            break;
+        }
+        let (na, nw, nh) = box_downsample_2x(&cur_a, w, h);
+        let (nb, _, _) = box_downsample_2x(&cur_b, w, h);
+        cur_a = na;
+        cur_b = nb;
+        w = nw;
+        h = nh;
+        downsample *= 2;
+    }
+
+    let total_weight: f64 = scales.iter().map(|s| s.weight).sum();
+    let coarsest = scales.len() - 1;
+
+    let ms_ssim = if total_weight > 0.0 {
+        scales
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let exponent = s.weight / total_weight;
+                let value = if i == coarsest {
+                    s.luminance * s.structure
+                } else {
+                    s.structure
+                };
+                value.max(1e-6).powf(exponent)
+            })
+            .product()
+    } else {
+        1.0
+    };
+
+    PerceptualScore { ms_ssim, scales }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +1006,37 @@ mod tests {
         assert!(s < 0.1, "ssim of opposite images should be low: {s}");
     }
 
+    #[test]
+    fn ms_ssim_identical_images() {
+        let img = vec![128u8; 64 * 64];
+        let report = ms_ssim(&img, &img, 64, 64);
+        assert!(
+            (report.ms_ssim - 1.0).abs() < 1e-6,
+            "ms_ssim of identical images: {}",
+            report.ms_ssim
+        );
+        assert!(report.scales.len() > 1, "expected multiple scales for 64x64");
+    }
+
+    #[test]
+    fn ms_ssim_different_images() {
+        let a = vec![0u8; 64 * 64];
+        let b = vec![255u8; 64 * 64];
+        let report = ms_ssim(&a, &b, 64, 64);
+        assert!(
+            report.ms_ssim < 0.1,
+            "ms_ssim of opposite images should be low: {}",
+            report.ms_ssim
+        );
+    }
+
+    #[test]
+    fn ms_ssim_falls_back_to_single_scale_for_small_images() {
+        let img = vec![128u8; 8 * 8];
+        let report = ms_ssim(&img, &img, 8, 8);
+        assert_eq!(report.scales.len(), 1);
+    }
+
     #[test]
     fn score_triangle_against_triangle() {
         // Generate a synthetic triangle filter curve
@@ -307,6 +1061,69 @@ mod tests {
             score.correlation
         );
         assert!(score.rms_error < 0.001, "rms: {}", score.rms_error);
+        assert!(
+            score.phase_offset.abs() < 0.02,
+            "offset: {}",
+            score.phase_offset
+        );
+    }
+
+    #[test]
+    fn score_detects_phase_shift() {
+        // A triangle filter curve sampled with a 0.3-pixel offset.
+        let true_shift = 0.3;
+        let points: Vec<(f64, f64)> = (-100..=100)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x + true_shift, KnownFilter::Triangle.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let score = score_against(&curve, KnownFilter::Triangle);
+        assert!(
+            (score.phase_offset - true_shift).abs() < 0.03,
+            "detected offset: {}",
+            score.phase_offset
+        );
+        assert!(
+            score.correlation > 0.999,
+            "correlation after correction: {}",
+            score.correlation
+        );
+    }
+
+    #[test]
+    fn score_detects_blur_factor() {
+        // A Lanczos3 curve with its support scaled by 0.85, as a "Sharp"
+        // variant would produce: same shape, stretched/compressed in x.
+        let true_s = 0.85;
+        let points: Vec<(f64, f64)> = (-300..=300)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::Lanczos3.evaluate(x / true_s) / true_s)
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let score = score_against(&curve, KnownFilter::Lanczos3);
+        assert!(
+            (score.blur_factor - true_s).abs() < 0.01,
+            "detected blur_factor: {}",
+            score.blur_factor
+        );
     }
 
     #[test]
@@ -329,4 +1146,164 @@ mod tests {
         assert_eq!(scores[0].filter, KnownFilter::Lanczos3);
         assert!(scores[0].correlation > 0.999);
     }
+
+    #[test]
+    fn fit_cubic_recovers_known_bc() {
+        let b = 0.25;
+        let c = 0.45;
+        let points: Vec<(f64, f64)> = (-200..=200)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::MitchellNetravali { b, c }.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_cubic(&curve);
+        assert!((fit.b - b).abs() < 1e-6, "B = {}", fit.b);
+        assert!((fit.c - c).abs() < 1e-6, "C = {}", fit.c);
+        assert!(fit.rms < 1e-9, "rms = {}", fit.rms);
+        // (0.25, 0.45) isn't close to any named preset.
+        assert!(fit.snapped.is_none(), "snapped = {:?}", fit.snapped);
+    }
+
+    #[test]
+    fn fit_cubic_snaps_to_catmull_rom() {
+        let points: Vec<(f64, f64)> = (-200..=200)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::CatmullRom.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_cubic(&curve);
+        assert_eq!(fit.snapped, Some(KnownFilter::CatmullRom));
+    }
+
+    #[test]
+    fn fit_cubic_snaps_to_hermite() {
+        let points: Vec<(f64, f64)> = (-200..=200)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::Hermite.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_cubic(&curve);
+        assert_eq!(fit.snapped, Some(KnownFilter::Hermite));
+    }
+
+    #[test]
+    fn fit_parametric_recovers_known_cubic() {
+        let b = 0.2;
+        let c = 0.4;
+        let points: Vec<(f64, f64)> = (-200..=200)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::MitchellNetravali { b, c }.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_parametric(&curve, FilterFamily::Cubic);
+        assert!((fit.params[0] - b).abs() < 0.01, "B = {}", fit.params[0]);
+        assert!((fit.params[1] - c).abs() < 0.01, "C = {}", fit.params[1]);
+        assert!(fit.rms < 1e-3, "rms = {}", fit.rms);
+    }
+
+    #[test]
+    fn fit_parametric_recovers_known_sinc() {
+        let family = FilterFamily::Sinc;
+        let true_params = vec![1.0, 2.5];
+        let points: Vec<(f64, f64)> = (-300..=300)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, family.evaluate(x, &true_params))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_parametric(&curve, family);
+        assert!(fit.rms < 1e-2, "rms = {}", fit.rms);
+        assert!(fit.correlation > 0.99, "correlation = {}", fit.correlation);
+    }
+
+    #[test]
+    fn fit_gaussian_recovers_known_sigma() {
+        let true_params = vec![0.8, 1.0];
+        let points: Vec<(f64, f64)> = (-300..=300)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, FilterFamily::Gaussian.evaluate(x, &true_params))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let fit = fit_gaussian(&curve);
+        assert!((fit.params[0] - true_params[0]).abs() < 0.01, "sigma = {}", fit.params[0]);
+        assert!((fit.params[1] - true_params[1]).abs() < 0.01, "A = {}", fit.params[1]);
+        assert!(fit.rms < 1e-3, "rms = {}", fit.rms);
+    }
+
+    #[test]
+    fn fit_parametric_report_picks_matching_family() {
+        let b = 0.2;
+        let c = 0.4;
+        let points: Vec<(f64, f64)> = (-200..=200)
+            .map(|i| {
+                let x = i as f64 / 100.0;
+                (x, KnownFilter::MitchellNetravali { b, c }.evaluate(x))
+            })
+            .collect();
+
+        let curve = FilterCurve {
+            points,
+            area: 1.0,
+            scale_factor: 37.0,
+            is_scatter: false,
+        };
+
+        let report = curve.fit_parametric();
+        assert_eq!(report.best.family, FilterFamily::Cubic);
+        assert_eq!(report.attempts.len(), 3);
+        assert!(report.best.rms < 1e-3, "rms = {}", report.best.rms);
+    }
 }
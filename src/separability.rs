@@ -0,0 +1,450 @@
+//! Probe whether a resizer's kernel is separable (applies independent
+//! horizontal and vertical passes, as in the classic scanline/weight-buffer
+//! filter model) or genuinely two-dimensional.
+
+use imgref::ImgRef;
+
+use crate::analyze::{self, read_pixel, FilterCurve};
+use crate::colorspace::Transfer;
+use crate::{check_dimensions, pattern, Error, ResizeFn};
+
+/// How far apart the reconstructed horizontal and vertical line-curve areas
+/// may be before the resizer is called anisotropic rather than isotropic.
+const ISOTROPIC_AREA_TOLERANCE: f64 = 0.05;
+
+/// Relative RMS residual of the averaged impulse patch against the rank-1
+/// (separable) approximation built from its row and column marginals, above
+/// which the kernel is called
+/// non-separable rather than separable-but-anisotropic.
+const NON_SEPARABLE_RESIDUAL_TOLERANCE: f64 = 0.05;
+
+/// Half-width, in pixels, of the 2D patch reconstructed around each
+/// impulse-grid dot.
+const PATCH_RADIUS: usize = 4;
+
+/// Whether a resizer's kernel acts independently per axis ("separable"),
+/// and if so, whether both axes use the same kernel ("isotropic").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separability {
+    /// The horizontal and vertical kernels match: a single 1D filter
+    /// applied independently on both axes.
+    SeparableIsotropic,
+    /// The horizontal and vertical kernels differ but each axis is still
+    /// independently separable, e.g. under an anisotropic scale factor.
+    SeparableAnisotropic,
+    /// The 2D impulse response doesn't factor into independent horizontal
+    /// and vertical kernels at all.
+    NonSeparable,
+}
+
+impl std::fmt::Display for Separability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SeparableIsotropic => f.write_str("SeparableIsotropic"),
+            Self::SeparableAnisotropic => f.write_str("SeparableAnisotropic"),
+            Self::NonSeparable => f.write_str("NonSeparable"),
+        }
+    }
+}
+
+/// A reconstructed local 2D weight patch around one impulse-grid dot, used
+/// to test whether the kernel factors into independent horizontal and
+/// vertical passes.
+#[derive(Debug, Clone)]
+pub struct ImpulsePatch {
+    /// The dot's coordinates in the (same-size) resized image.
+    pub center: (usize, usize),
+    /// `weights[row][col]`, indexed by offset from the patch center.
+    pub weights: Vec<Vec<f64>>,
+}
+
+/// Result of probing a resizer's separability.
+#[derive(Debug, Clone)]
+pub struct SeparabilityResult {
+    pub classification: Separability,
+    /// Reconstructed horizontal kernel (line pattern, 15->555).
+    pub horizontal: FilterCurve,
+    /// Reconstructed vertical kernel, via a transposed line pattern.
+    pub vertical: FilterCurve,
+    /// The averaged 2D impulse patch's deviation from the rank-1 (separable)
+    /// approximation built from its row and column marginals. Only probed once the horizontal and
+    /// vertical curves already disagree, since a match there is already
+    /// evidence of separability.
+    pub impulse_residual: Option<f64>,
+}
+
+/// Probe the vertical-axis kernel by transposing the line pattern, letting
+/// the resizer act on what was previously the untouched axis, then
+/// transposing the result back so [`analyze::analyze_line`] can reconstruct
+/// it with the same code used for the horizontal probe.
+fn probe_vertical_line(resize: &ResizeFn, transfer: Transfer) -> Result<FilterCurve, Error> {
+    let src = pattern::generate_line_pattern_vertical();
+    let (dst_w, dst_h) = analyze::line_target();
+    let resized = resize(src.as_ref(), dst_h, dst_w);
+    check_dimensions(&resized, dst_h, dst_w)?;
+    let resized = pattern::transpose(&resized.as_ref());
+    Ok(analyze::analyze_line(&resized.as_ref(), transfer))
+}
+
+/// Reconstruct a local weight patch around each known impulse-grid center
+/// from a same-size resize of [`pattern::generate_impulse_grid_pattern`].
+fn analyze_impulse_patches(
+    img: &ImgRef<'_, u8>,
+    centers: &[(usize, usize)],
+    transfer: &Transfer,
+) -> Vec<ImpulsePatch> {
+    let lut = transfer.decode_lut();
+    let w = img.width();
+    let h = img.height();
+
+    centers
+        .iter()
+        .filter_map(|&(cx, cy)| {
+            if cx < PATCH_RADIUS || cy < PATCH_RADIUS {
+                return None;
+            }
+            if cx + PATCH_RADIUS >= w || cy + PATCH_RADIUS >= h {
+                return None;
+            }
+
+            let mut weights = vec![vec![0.0; 2 * PATCH_RADIUS + 1]; 2 * PATCH_RADIUS + 1];
+            for (row, weights_row) in weights.iter_mut().enumerate() {
+                let y = cy + row - PATCH_RADIUS;
+                for (col, slot) in weights_row.iter_mut().enumerate() {
+                    let x = cx + col - PATCH_RADIUS;
+                    let v = read_pixel(img, x, y, transfer, &lut);
+                    let span = pattern::BRIGHT as f64 - pattern::DARK as f64;
+                    *slot = (v - pattern::DARK as f64) / span;
+                }
+            }
+
+            Some(ImpulsePatch { center: (cx, cy), weights })
+        })
+        .collect()
+}
+
+/// Average a set of impulse patches into one, to reduce per-dot noise.
+fn average_patch(patches: &[ImpulsePatch]) -> Option<Vec<Vec<f64>>> {
+    let size = patches.first()?.weights.len();
+    let mut sum = vec![vec![0.0; size]; size];
+
+    for patch in patches {
+        for (row, patch_row) in patch.weights.iter().enumerate() {
+            for (col, &v) in patch_row.iter().enumerate() {
+                sum[row][col] += v;
+            }
+        }
+    }
+
+    let n = patches.len() as f64;
+    for row in &mut sum {
+        for v in row {
+            *v /= n;
+        }
+    }
+
+    Some(sum)
+}
+
+/// Relative RMS residual of the averaged impulse patch against a rank-1
+/// (separable) approximation: the outer product of its row and
+/// column marginal sums, normalized by total weight. A genuinely separable
+/// 2D kernel factors exactly into `h(x) * v(y)`, so this residual is ~0 for
+/// one and meaningfully nonzero for a kernel that doesn't factor, such as a
+/// circular (non-separable) blur.
+fn separability_residual(patches: &[ImpulsePatch]) -> f64 {
+    let Some(patch) = average_patch(patches) else {
+        return 0.0;
+    };
+    let size = patch.len();
+
+    let row_sums: Vec<f64> = patch.iter().map(|row| row.iter().sum()).collect();
+    let col_sums: Vec<f64> = (0..size).map(|c| patch.iter().map(|row| row[c]).sum()).collect();
+    let total: f64 = row_sums.iter().sum();
+    if total.abs() < 1e-9 {
+        return 0.0;
+    }
+
+    let mut sq_error = 0.0;
+    let mut sq_total = 0.0;
+    for r in 0..size {
+        for c in 0..size {
+            let approx = row_sums[r] * col_sums[c] / total;
+            sq_error += (patch[r][c] - approx).powi(2);
+            sq_total += patch[r][c].powi(2);
+        }
+    }
+
+    if sq_total < 1e-12 {
+        0.0
+    } else {
+        (sq_error / sq_total).sqrt()
+    }
+}
+
+/// Probe a resizer's separability: reconstruct the horizontal and vertical
+/// kernels independently and compare them.
+///
+/// If the two curves' areas agree within `ISOTROPIC_AREA_TOLERANCE`, the
+/// resizer is [`Separability::SeparableIsotropic`]; their shapes are not
+/// compared. If the areas differ (common under an anisotropic scale factor), a sparse 2D
+/// impulse grid ([`pattern::generate_impulse_grid_pattern`]) is resized
+/// 1:1 and the averaged local weight patch around each dot is tested
+/// against the rank-1 approximation built from its row and column
+/// marginals, to tell a resizer that still
+/// applies independent (but different) H and V kernels
+/// ([`Separability::SeparableAnisotropic`]) apart from one whose kernel is
+/// genuinely two-dimensional ([`Separability::NonSeparable`]).
+pub fn detect(resize: &ResizeFn, transfer: Transfer) -> Result<SeparabilityResult, Error> {
+    let line_src = pattern::generate_line_pattern();
+    let (line_w, line_h) = analyze::line_target();
+    let h_resized = resize(line_src.as_ref(), line_w, line_h);
+    check_dimensions(&h_resized, line_w, line_h)?;
+    let horizontal = analyze::analyze_line(&h_resized.as_ref(), transfer.clone());
+
+    let vertical = probe_vertical_line(resize, transfer.clone())?;
+
+    let area_diff = (horizontal.area - vertical.area).abs();
+    if area_diff <= ISOTROPIC_AREA_TOLERANCE {
+        return Ok(SeparabilityResult {
+            classification: Separability::SeparableIsotropic,
+            horizontal,
+            vertical,
+            impulse_residual: None,
+        });
+    }
+
+    let grid_src = pattern::generate_impulse_grid_pattern();
+    let size = grid_src.width();
+    let grid_resized = resize(grid_src.as_ref(), size, size);
+    check_dimensions(&grid_resized, size, size)?;
+    let patches = analyze_impulse_patches(
+        &grid_resized.as_ref(),
+        &pattern::impulse_grid_centers(),
+        &transfer,
+    );
+    let residual = separability_residual(&patches);
+
+    let classification = if residual <= NON_SEPARABLE_RESIDUAL_TOLERANCE {
+        Separability::SeparableAnisotropic
+    } else {
+        Separability::NonSeparable
+    };
+
+    Ok(SeparabilityResult {
+        classification,
+        horizontal,
+        vertical,
+        impulse_residual: Some(residual),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imgref::ImgVec;
+
+    /// Nearest-neighbor resize, identical on both axes, for testing.
+    fn nn_resize(src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize) -> ImgVec<u8> {
+        let mut dst = vec![0u8; dst_w * dst_h];
+        for y in 0..dst_h {
+            let sy = ((y as f64 + 0.5) * src.height() as f64 / dst_h as f64 - 0.5)
+                .round()
+                .clamp(0.0, (src.height() - 1) as f64) as usize;
+            for x in 0..dst_w {
+                let sx = ((x as f64 + 0.5) * src.width() as f64 / dst_w as f64 - 0.5)
+                    .round()
+                    .clamp(0.0, (src.width() - 1) as f64) as usize;
+                dst[y * dst_w + x] = src.buf()[sy * src.stride() + sx];
+            }
+        }
+        ImgVec::new(dst, dst_w, dst_h)
+    }
+
+    #[test]
+    fn nn_resize_is_separable_isotropic() {
+        let result = detect(&nn_resize, Transfer::Linear).unwrap();
+        assert_eq!(result.classification, Separability::SeparableIsotropic);
+        assert!(result.impulse_residual.is_none());
+    }
+
+    /// Out-of-bounds taps beyond the source image read as zero, same
+    /// convention as `edge::zero_extend`.
+    fn zero_extend(i: isize, length: usize) -> Option<usize> {
+        if i < 0 || i >= length as isize {
+            None
+        } else {
+            Some(i as usize)
+        }
+    }
+
+    /// Separable tensor-product tent-filter resize with independent
+    /// integer radii per axis, so the horizontal and vertical passes
+    /// genuinely differ in width while each axis stays independently
+    /// separable (`h(x) * v(y)`, just with `h` and `v` of different
+    /// support). Zero-pads rather than clamping so the narrow test image
+    /// actually loses energy near a wide-enough axis's boundary, which is
+    /// what makes the reconstructed horizontal/vertical areas disagree
+    /// (a real anisotropic kernel with a radius this close to the test
+    /// pattern's extent, not a synthetic area mismatch).
+    fn aniso_resize(
+        radius_h: i64,
+        radius_v: i64,
+    ) -> impl Fn(ImgRef<'_, u8>, usize, usize) -> ImgVec<u8> {
+        move |src, dst_w, dst_h| {
+            let src_w = src.width();
+            let src_h = src.height();
+            let tri = |d: f64, r: i64| (1.0 - d.abs() / r as f64).max(0.0);
+            let sample = |row: isize, col: isize| -> Option<f64> {
+                let r = zero_extend(row, src_h)?;
+                let c = zero_extend(col, src_w)?;
+                Some(src.buf()[r * src.stride() + c] as f64)
+            };
+
+            let mut dst = vec![0u8; dst_w * dst_h];
+            for y in 0..dst_h {
+                let sy = (y as f64 + 0.5) * src_h as f64 / dst_h as f64 - 0.5;
+                let cy = sy.round() as i64;
+                for x in 0..dst_w {
+                    let sx = (x as f64 + 0.5) * src_w as f64 / dst_w as f64 - 0.5;
+                    let cx = sx.round() as i64;
+
+                    let mut total = 0.0;
+                    for ky in (cy - radius_v - 1)..=(cy + radius_v + 1) {
+                        let wy = tri(ky as f64 - sy, radius_v);
+                        if wy <= 0.0 {
+                            continue;
+                        }
+                        for kx in (cx - radius_h - 1)..=(cx + radius_h + 1) {
+                            let wx = tri(kx as f64 - sx, radius_h);
+                            if wx <= 0.0 {
+                                continue;
+                            }
+                            if let Some(v) = sample(ky as isize, kx as isize) {
+                                total += wx * wy * v;
+                            }
+                        }
+                    }
+
+                    let v = total / (radius_h * radius_v) as f64;
+                    dst[y * dst_w + x] = v.round().clamp(0.0, 255.0) as u8;
+                }
+            }
+            ImgVec::new(dst, dst_w, dst_h)
+        }
+    }
+
+    #[test]
+    fn aniso_kernel_is_separable_anisotropic() {
+        let resize = aniso_resize(1, 9);
+        let result = detect(&resize, Transfer::Linear).unwrap();
+        assert_eq!(result.classification, Separability::SeparableAnisotropic);
+        let residual = result.impulse_residual.expect("anisotropic path probes the grid");
+        assert!(residual <= NON_SEPARABLE_RESIDUAL_TOLERANCE, "residual = {residual}");
+    }
+
+    /// A tent-shaped patch rotated off-axis: genuinely non-separable, since
+    /// it can't be written as any `h(x) * v(y)` in image coordinates (unlike
+    /// [`aniso_resize`], which stays a tensor product just with unequal
+    /// radii). Zero-pads with a fixed, non-adaptive divisor for the same
+    /// reason as `aniso_resize`: so the test image's boundary genuinely
+    /// loses energy rather than being renormalized away, which is what
+    /// breaks the horizontal/vertical area match and keeps `detect` from
+    /// shortcutting to `SeparableIsotropic` before it ever reaches the 2D
+    /// patch check.
+    fn rotated_resize() -> impl Fn(ImgRef<'_, u8>, usize, usize) -> ImgVec<u8> {
+        const THETA_DEG: f64 = 30.0;
+        const RADIUS_A: f64 = 2.0;
+        const RADIUS_B: f64 = 6.0;
+
+        let (sin_t, cos_t) = THETA_DEG.to_radians().sin_cos();
+        let weight = move |dx: f64, dy: f64| -> f64 {
+            let u = dx * cos_t + dy * sin_t;
+            let v = -dx * sin_t + dy * cos_t;
+            let tri = |d: f64, r: f64| (1.0 - d.abs() / r).max(0.0);
+            tri(u, RADIUS_A) * tri(v, RADIUS_B)
+        };
+
+        let half_width = RADIUS_A.max(RADIUS_B) as i64 + 2;
+        let mut divisor = 0.0;
+        for ky in -half_width..=half_width {
+            for kx in -half_width..=half_width {
+                divisor += weight(kx as f64, ky as f64);
+            }
+        }
+
+        move |src, dst_w, dst_h| {
+            let src_w = src.width();
+            let src_h = src.height();
+            let sample = |row: isize, col: isize| -> Option<f64> {
+                let r = zero_extend(row, src_h)?;
+                let c = zero_extend(col, src_w)?;
+                Some(src.buf()[r * src.stride() + c] as f64)
+            };
+
+            let mut dst = vec![0u8; dst_w * dst_h];
+            for y in 0..dst_h {
+                let sy = (y as f64 + 0.5) * src_h as f64 / dst_h as f64 - 0.5;
+                let cy = sy.round() as i64;
+                for x in 0..dst_w {
+                    let sx = (x as f64 + 0.5) * src_w as f64 / dst_w as f64 - 0.5;
+                    let cx = sx.round() as i64;
+
+                    let mut total = 0.0;
+                    for ky in (cy - half_width)..=(cy + half_width) {
+                        for kx in (cx - half_width)..=(cx + half_width) {
+                            let w = weight(kx as f64 - sx, ky as f64 - sy);
+                            if w <= 0.0 {
+                                continue;
+                            }
+                            if let Some(v) = sample(ky as isize, kx as isize) {
+                                total += w * v;
+                            }
+                        }
+                    }
+
+                    let v = total / divisor;
+                    dst[y * dst_w + x] = v.round().clamp(0.0, 255.0) as u8;
+                }
+            }
+            ImgVec::new(dst, dst_w, dst_h)
+        }
+    }
+
+    #[test]
+    fn rotated_kernel_is_non_separable() {
+        let resize = rotated_resize();
+        let result = detect(&resize, Transfer::Linear).unwrap();
+        assert_eq!(result.classification, Separability::NonSeparable);
+        let residual = result.impulse_residual.expect("non-isotropic path probes the grid");
+        assert!(residual > NON_SEPARABLE_RESIDUAL_TOLERANCE, "residual = {residual}");
+    }
+
+    #[test]
+    fn rank1_patch_has_near_zero_residual() {
+        let patch = ImpulsePatch {
+            center: (0, 0),
+            weights: vec![
+                vec![1.0, 2.0, 1.0],
+                vec![2.0, 4.0, 2.0],
+                vec![1.0, 2.0, 1.0],
+            ],
+        };
+        let residual = separability_residual(&[patch]);
+        assert!(residual < 1e-9, "residual = {residual}");
+    }
+
+    #[test]
+    fn non_rank1_patch_has_large_residual() {
+        let patch = ImpulsePatch {
+            center: (0, 0),
+            weights: vec![
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 1.0],
+            ],
+        };
+        let residual = separability_residual(&[patch]);
+        assert!(residual > NON_SEPARABLE_RESIDUAL_TOLERANCE, "residual = {residual}");
+    }
+}
@@ -0,0 +1,583 @@
+//! Stable text serialization of [`AnalysisResult`](crate::AnalysisResult) for
+//! regression baselines.
+//!
+//! A snapshot is a single JSON header line (format version, edge mode, and
+//! per-curve metadata) followed by a CSV body of `(curve, offset, weight)`
+//! rows. This lets a known-good result for any external resizer be frozen
+//! once, committed as a fixture, and compared against on later runs without
+//! needing the `c-reference` build.
+
+use crate::analyze::FilterCurve;
+use crate::edge::EdgeMode;
+
+/// Current snapshot format version. Bump when the header or row layout
+/// changes in a way that isn't backward compatible.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Error loading or parsing a snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("snapshot is missing its header line")]
+    MissingHeader,
+    #[error("invalid snapshot header: {0}")]
+    InvalidHeader(String),
+    #[error("invalid snapshot row: {0}")]
+    InvalidRow(String),
+    #[error("unsupported snapshot version {0} (expected {SNAPSHOT_VERSION})")]
+    UnsupportedVersion(u32),
+}
+
+/// A single curve's metadata and sample points, as frozen in a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveSnapshot {
+    pub area: f64,
+    pub scale_factor: f64,
+    pub is_scatter: bool,
+    pub points: Vec<(f64, f64)>,
+}
+
+impl CurveSnapshot {
+    fn from_curve(curve: &FilterCurve) -> Self {
+        Self {
+            area: curve.area,
+            scale_factor: curve.scale_factor,
+            is_scatter: curve.is_scatter,
+            points: curve.points.clone(),
+        }
+    }
+}
+
+/// A frozen, versioned snapshot of an [`AnalysisResult`](crate::AnalysisResult).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisSnapshot {
+    pub downscale: Option<CurveSnapshot>,
+    pub upscale: Option<CurveSnapshot>,
+    pub edge_mode: Option<EdgeMode>,
+}
+
+impl AnalysisSnapshot {
+    pub(crate) fn from_result(
+        downscale_curve: Option<&FilterCurve>,
+        upscale_curve: Option<&FilterCurve>,
+        edge_mode: Option<EdgeMode>,
+    ) -> Self {
+        Self {
+            downscale: downscale_curve.map(CurveSnapshot::from_curve),
+            upscale: upscale_curve.map(CurveSnapshot::from_curve),
+            edge_mode,
+        }
+    }
+
+    /// Serialize to the stable text format: a JSON header line, a CSV
+    /// column header, then one row per sample point.
+    pub fn to_text(&self) -> String {
+        let mut out = header_json(self);
+        out.push('\n');
+        out.push_str("curve,offset,weight\n");
+        if let Some(curve) = &self.downscale {
+            for (offset, weight) in &curve.points {
+                out.push_str(&format!("downscale,{offset},{weight}\n"));
+            }
+        }
+        if let Some(curve) = &self.upscale {
+            for (offset, weight) in &curve.points {
+                out.push_str(&format!("upscale,{offset},{weight}\n"));
+            }
+        }
+        out
+    }
+
+    /// Parse the text format produced by [`Self::to_text`].
+    pub fn from_text(text: &str) -> Result<Self, SnapshotError> {
+        let mut lines = text.lines();
+        let header_line = lines.next().ok_or(SnapshotError::MissingHeader)?;
+        let header = parse_json(header_line)?;
+        let fields = match &header {
+            JsonValue::Object(fields) => fields,
+            _ => return Err(SnapshotError::InvalidHeader("header is not an object".into())),
+        };
+
+        let version = get_number(fields, "version")? as u32;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let edge_mode = match get_string(fields, "edge_mode") {
+            Some(s) => Some(parse_edge_mode(s)?),
+            None => None,
+        };
+
+        let downscale_meta = curve_meta_from_json(get_field(fields, "downscale"))?;
+        let upscale_meta = curve_meta_from_json(get_field(fields, "upscale"))?;
+
+        let csv_header = lines
+            .next()
+            .ok_or_else(|| SnapshotError::InvalidRow("missing CSV header row".into()))?;
+        if csv_header.trim() != "curve,offset,weight" {
+            return Err(SnapshotError::InvalidRow(format!(
+                "unexpected CSV header: {csv_header}"
+            )));
+        }
+
+        let mut downscale_points = Vec::new();
+        let mut upscale_points = Vec::new();
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut parts = line.splitn(3, ',');
+            let curve = parts
+                .next()
+                .ok_or_else(|| SnapshotError::InvalidRow(line.to_string()))?;
+            let offset: f64 = parts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| SnapshotError::InvalidRow(line.to_string()))?;
+            let weight: f64 = parts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| SnapshotError::InvalidRow(line.to_string()))?;
+            match curve {
+                "downscale" => downscale_points.push((offset, weight)),
+                "upscale" => upscale_points.push((offset, weight)),
+                other => {
+                    return Err(SnapshotError::InvalidRow(format!(
+                        "unknown curve label '{other}'"
+                    )))
+                }
+            }
+        }
+
+        let downscale = downscale_meta
+            .map(|meta| finish_curve(meta, downscale_points))
+            .transpose()?;
+        let upscale = upscale_meta
+            .map(|meta| finish_curve(meta, upscale_points))
+            .transpose()?;
+
+        Ok(AnalysisSnapshot {
+            downscale,
+            upscale,
+            edge_mode,
+        })
+    }
+}
+
+/// Metadata for one curve, as read from the header before its points have
+/// been collected from the CSV body.
+struct CurveMeta {
+    scale_factor: f64,
+    area: f64,
+    is_scatter: bool,
+    point_count: usize,
+}
+
+fn finish_curve(
+    meta: CurveMeta,
+    points: Vec<(f64, f64)>,
+) -> Result<CurveSnapshot, SnapshotError> {
+    if points.len() != meta.point_count {
+        return Err(SnapshotError::InvalidRow(format!(
+            "header declared {} points but {} rows were read",
+            meta.point_count,
+            points.len()
+        )));
+    }
+    Ok(CurveSnapshot {
+        area: meta.area,
+        scale_factor: meta.scale_factor,
+        is_scatter: meta.is_scatter,
+        points,
+    })
+}
+
+fn curve_meta_from_json(
+    value: Option<&JsonValue>,
+) -> Result<Option<CurveMeta>, SnapshotError> {
+    match value {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Object(fields)) => Ok(Some(CurveMeta {
+            scale_factor: get_number(fields, "scale_factor")?,
+            area: get_number(fields, "area")?,
+            is_scatter: get_bool(fields, "is_scatter")?,
+            point_count: get_number(fields, "point_count")? as usize,
+        })),
+        Some(_) => Err(SnapshotError::InvalidHeader(
+            "expected a curve object or null".into(),
+        )),
+    }
+}
+
+fn parse_edge_mode(s: &str) -> Result<EdgeMode, SnapshotError> {
+    match s {
+        "Clamp" => Ok(EdgeMode::Clamp),
+        "Reflect" => Ok(EdgeMode::Reflect),
+        "Wrap" => Ok(EdgeMode::Wrap),
+        "Zero" => Ok(EdgeMode::Zero),
+        "Unknown" => Ok(EdgeMode::Unknown),
+        other => Err(SnapshotError::InvalidHeader(format!(
+            "unknown edge mode '{other}'"
+        ))),
+    }
+}
+
+fn header_json(snapshot: &AnalysisSnapshot) -> String {
+    let edge_mode = match snapshot.edge_mode {
+        Some(mode) => format!("\"{mode}\""),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"version\":{},\"edge_mode\":{},\"downscale\":{},\"upscale\":{}}}",
+        SNAPSHOT_VERSION,
+        edge_mode,
+        curve_meta_json(&snapshot.downscale),
+        curve_meta_json(&snapshot.upscale),
+    )
+}
+
+fn curve_meta_json(curve: &Option<CurveSnapshot>) -> String {
+    match curve {
+        None => "null".to_string(),
+        Some(c) => format!(
+            "{{\"scale_factor\":{},\"area\":{},\"is_scatter\":{},\"point_count\":{}}}",
+            c.scale_factor,
+            c.area,
+            c.is_scatter,
+            c.points.len()
+        ),
+    }
+}
+
+/// The result of comparing a snapshot against a baseline, for regression
+/// testing against any external resizer's output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotDiff {
+    pub max_offset_error: f64,
+    pub max_weight_error: f64,
+    pub edge_mode_matches: bool,
+    pub within_tolerance: bool,
+}
+
+/// Compare a candidate snapshot against a baseline, reporting the largest
+/// per-point offset/weight error and whether every check is within
+/// `tolerance`. A curve present in one snapshot but not the other, or with
+/// a different point count, reports infinite error rather than panicking.
+pub fn compare(
+    baseline: &AnalysisSnapshot,
+    candidate: &AnalysisSnapshot,
+    tolerance: f64,
+) -> SnapshotDiff {
+    let (downscale_offset, downscale_weight) =
+        compare_curves(&baseline.downscale, &candidate.downscale);
+    let (upscale_offset, upscale_weight) = compare_curves(&baseline.upscale, &candidate.upscale);
+
+    let max_offset_error = downscale_offset.max(upscale_offset);
+    let max_weight_error = downscale_weight.max(upscale_weight);
+    let edge_mode_matches = baseline.edge_mode == candidate.edge_mode;
+    let within_tolerance =
+        max_offset_error <= tolerance && max_weight_error <= tolerance && edge_mode_matches;
+
+    SnapshotDiff {
+        max_offset_error,
+        max_weight_error,
+        edge_mode_matches,
+        within_tolerance,
+    }
+}
+
+fn compare_curves(a: &Option<CurveSnapshot>, b: &Option<CurveSnapshot>) -> (f64, f64) {
+    match (a, b) {
+        (None, None) => (0.0, 0.0),
+        (Some(a), Some(b)) if a.points.len() == b.points.len() => {
+            let mut max_offset = 0.0_f64;
+            let mut max_weight = 0.0_f64;
+            for ((a_offset, a_weight), (b_offset, b_weight)) in a.points.iter().zip(&b.points) {
+                max_offset = max_offset.max((a_offset - b_offset).abs());
+                max_weight = max_weight.max((a_weight - b_weight).abs());
+            }
+            (max_offset, max_weight)
+        }
+        _ => (f64::INFINITY, f64::INFINITY),
+    }
+}
+
+// ---- Minimal hand-rolled JSON, just enough for the flat header above. ----
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Object(Vec<(String, JsonValue)>),
+}
+
+fn get_field<'a>(fields: &'a [(String, JsonValue)], key: &str) -> Option<&'a JsonValue> {
+    fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+}
+
+fn get_number(fields: &[(String, JsonValue)], key: &str) -> Result<f64, SnapshotError> {
+    match get_field(fields, key) {
+        Some(JsonValue::Number(n)) => Ok(*n),
+        _ => Err(SnapshotError::InvalidHeader(format!(
+            "missing or invalid '{key}'"
+        ))),
+    }
+}
+
+fn get_bool(fields: &[(String, JsonValue)], key: &str) -> Result<bool, SnapshotError> {
+    match get_field(fields, key) {
+        Some(JsonValue::Bool(b)) => Ok(*b),
+        _ => Err(SnapshotError::InvalidHeader(format!(
+            "missing or invalid '{key}'"
+        ))),
+    }
+}
+
+fn get_string<'a>(fields: &'a [(String, JsonValue)], key: &str) -> Option<&'a str> {
+    match get_field(fields, key) {
+        Some(JsonValue::String(s)) => Some(s.as_str()),
+        _ => None,
+    }
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+fn parse_json(text: &str) -> Result<JsonValue, SnapshotError> {
+    let mut parser = JsonParser {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    parser.parse_value()
+}
+
+impl<'a> JsonParser<'a> {
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), SnapshotError> {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(SnapshotError::InvalidHeader(format!(
+                "expected '{}' at byte {}",
+                b as char, self.pos
+            )))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, SnapshotError> {
+        self.skip_ws();
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b't') | Some(b'f') => self.parse_bool(),
+            Some(b'n') => self.parse_null(),
+            Some(_) => self.parse_number(),
+            None => Err(SnapshotError::InvalidHeader("unexpected end of header".into())),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, SnapshotError> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+        loop {
+            self.skip_ws();
+            let key = self.parse_string()?;
+            self.skip_ws();
+            self.expect(b':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(SnapshotError::InvalidHeader("malformed object".into())),
+            }
+        }
+        Ok(JsonValue::Object(entries))
+    }
+
+    fn parse_string(&mut self) -> Result<String, SnapshotError> {
+        self.expect(b'"')?;
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(b'"') => {
+                            s.push('"');
+                            self.pos += 1;
+                        }
+                        Some(b'\\') => {
+                            s.push('\\');
+                            self.pos += 1;
+                        }
+                        _ => return Err(SnapshotError::InvalidHeader("bad escape sequence".into())),
+                    }
+                }
+                Some(c) => {
+                    s.push(c as char);
+                    self.pos += 1;
+                }
+                None => return Err(SnapshotError::InvalidHeader("unterminated string".into())),
+            }
+        }
+        Ok(s)
+    }
+
+    fn parse_bool(&mut self) -> Result<JsonValue, SnapshotError> {
+        if self.bytes[self.pos..].starts_with(b"true") {
+            self.pos += 4;
+            Ok(JsonValue::Bool(true))
+        } else if self.bytes[self.pos..].starts_with(b"false") {
+            self.pos += 5;
+            Ok(JsonValue::Bool(false))
+        } else {
+            Err(SnapshotError::InvalidHeader("expected a boolean".into()))
+        }
+    }
+
+    fn parse_null(&mut self) -> Result<JsonValue, SnapshotError> {
+        if self.bytes[self.pos..].starts_with(b"null") {
+            self.pos += 4;
+            Ok(JsonValue::Null)
+        } else {
+            Err(SnapshotError::InvalidHeader("expected null".into()))
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, SnapshotError> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'0'..=b'9') | Some(b'-') | Some(b'+') | Some(b'.') | Some(b'e') | Some(b'E')
+        ) {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        text.parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| SnapshotError::InvalidHeader(format!("invalid number: {text}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_snapshot() -> AnalysisSnapshot {
+        AnalysisSnapshot {
+            downscale: Some(CurveSnapshot {
+                area: 0.0,
+                scale_factor: 0.996,
+                is_scatter: true,
+                points: vec![(-2.0, 0.01), (0.0, 0.9), (2.0, 0.01)],
+            }),
+            upscale: Some(CurveSnapshot {
+                area: 1.0,
+                scale_factor: 37.0,
+                is_scatter: false,
+                points: vec![(-1.0, 0.0), (0.0, 1.0), (1.0, 0.0)],
+            }),
+            edge_mode: Some(EdgeMode::Clamp),
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_points() {
+        let original = sample_snapshot();
+        let text = original.to_text();
+        let parsed = AnalysisSnapshot::from_text(&text).expect("should parse");
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn round_trip_handles_missing_curves_and_edge_mode() {
+        let original = AnalysisSnapshot {
+            downscale: None,
+            upscale: Some(CurveSnapshot {
+                area: 1.0,
+                scale_factor: 37.0,
+                is_scatter: false,
+                points: vec![(0.0, 1.0)],
+            }),
+            edge_mode: None,
+        };
+        let text = original.to_text();
+        let parsed = AnalysisSnapshot::from_text(&text).expect("should parse");
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn version_mismatch_is_rejected() {
+        let text = sample_snapshot()
+            .to_text()
+            .replacen("\"version\":1", "\"version\":2", 1);
+        let err = AnalysisSnapshot::from_text(&text).unwrap_err();
+        assert!(matches!(err, SnapshotError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn point_count_mismatch_is_rejected() {
+        let mut text = sample_snapshot().to_text();
+        text.push_str("downscale,3.0,0.0\n");
+        let err = AnalysisSnapshot::from_text(&text).unwrap_err();
+        assert!(matches!(err, SnapshotError::InvalidRow(_)));
+    }
+
+    #[test]
+    fn compare_reports_zero_diff_for_identical_snapshots() {
+        let snapshot = sample_snapshot();
+        let diff = compare(&snapshot, &snapshot, 1e-9);
+        assert_eq!(diff.max_offset_error, 0.0);
+        assert_eq!(diff.max_weight_error, 0.0);
+        assert!(diff.within_tolerance);
+    }
+
+    #[test]
+    fn compare_flags_drift_beyond_tolerance() {
+        let baseline = sample_snapshot();
+        let mut candidate = baseline.clone();
+        candidate.upscale.as_mut().unwrap().points[1].1 += 0.05;
+
+        let diff = compare(&baseline, &candidate, 0.01);
+        assert!((diff.max_weight_error - 0.05).abs() < 1e-9);
+        assert!(!diff.within_tolerance);
+    }
+
+    #[test]
+    fn compare_treats_mismatched_curve_presence_as_infinite_error() {
+        let baseline = sample_snapshot();
+        let mut candidate = baseline.clone();
+        candidate.downscale = None;
+
+        let diff = compare(&baseline, &candidate, 1.0);
+        assert!(diff.max_offset_error.is_infinite());
+        assert!(!diff.within_tolerance);
+    }
+}
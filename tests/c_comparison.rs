@@ -139,7 +139,7 @@ fn dot_analysis_matches_c() {
 
     // Run Rust analysis.
     let config = resamplescope::AnalysisConfig {
-        srgb: false,
+        transfer: resamplescope::Transfer::Linear,
         detect_edges: false,
     };
     let rust_result = resamplescope::analyze_downscale(&nn_resize, &config).unwrap();
@@ -217,7 +217,7 @@ fn line_analysis_matches_c() {
 
     // Run Rust analysis.
     let config = resamplescope::AnalysisConfig {
-        srgb: false,
+        transfer: resamplescope::Transfer::Linear,
         detect_edges: false,
     };
     let rust_result = resamplescope::analyze_upscale(&nn_resize, &config).unwrap();
@@ -301,7 +301,7 @@ fn line_analysis_with_srgb_matches_c() {
 
     // Rust analysis with sRGB.
     let config = resamplescope::AnalysisConfig {
-        srgb: true,
+        transfer: resamplescope::Transfer::Srgb,
         detect_edges: false,
     };
     let resize_fn = |src: ImgRef<'_, u8>, w: usize, h: usize| -> ImgVec<u8> {
@@ -370,7 +370,7 @@ fn dot_analysis_perfect_resize_matches_c() {
         resamplescope::perfect_resize(src, w, h, KnownFilter::Lanczos3)
     };
     let config = resamplescope::AnalysisConfig {
-        srgb: false,
+        transfer: resamplescope::Transfer::Linear,
         detect_edges: false,
     };
     let rust_result = resamplescope::analyze_downscale(&resize_fn, &config).unwrap();
@@ -1,5 +1,5 @@
 use imgref::{ImgRef, ImgVec};
-use resamplescope::{AnalysisConfig, KnownFilter};
+use resamplescope::{AnalysisConfig, KnownFilter, Transfer};
 
 /// Simple box (nearest-neighbor) resize for testing.
 fn nn_resize(src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize) -> ImgVec<u8> {
@@ -55,7 +55,7 @@ fn bilinear_resize(src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize) -> ImgVec<u8
 #[test]
 fn box_filter_detection() {
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze(&nn_resize, &config).unwrap();
@@ -76,7 +76,7 @@ fn box_filter_detection() {
 #[test]
 fn triangle_filter_detection() {
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze(&bilinear_resize, &config).unwrap();
@@ -103,7 +103,7 @@ fn perfect_resize_detected_correctly() {
     };
 
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze(&resize_fn, &config).unwrap();
@@ -143,7 +143,7 @@ fn ssim_perfect_vs_nn() {
 #[test]
 fn graph_rendering() {
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze(&nn_resize, &config).unwrap();
@@ -158,6 +158,19 @@ fn graph_rendering() {
     assert_eq!(graph_ref.height(), 300);
 }
 
+#[test]
+fn graph_svg_rendering() {
+    let config = AnalysisConfig {
+        transfer: Transfer::Linear,
+        detect_edges: false,
+    };
+    let result = resamplescope::analyze(&nn_resize, &config).unwrap();
+
+    let svg = result.render_graph_svg(Some(KnownFilter::Box));
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("<circle"), "expected scatter points as circles");
+}
+
 #[test]
 fn weight_table_correctness() {
     // Verify that applying computed weights to a constant image produces the same constant.
@@ -182,7 +195,7 @@ fn weight_table_correctness() {
 #[test]
 fn upscale_only_analysis() {
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze_upscale(&bilinear_resize, &config).unwrap();
@@ -194,10 +207,74 @@ fn upscale_only_analysis() {
     assert_eq!(best.filter, KnownFilter::Triangle);
 }
 
+#[test]
+fn fit_cubic_identifies_tuned_mitchell() {
+    let b = 0.2;
+    let c = 0.4;
+    let resize_fn = move |src: ImgRef<'_, u8>, w: usize, h: usize| -> ImgVec<u8> {
+        resamplescope::perfect_resize(src, w, h, KnownFilter::MitchellNetravali { b, c })
+    };
+
+    let config = AnalysisConfig {
+        transfer: Transfer::Linear,
+        detect_edges: false,
+    };
+    let result = resamplescope::analyze(&resize_fn, &config).unwrap();
+
+    let fit = result.fit_cubic().expect("fit_cubic should produce a result");
+    assert!((fit.b - b).abs() < 0.05, "B = {}", fit.b);
+    assert!((fit.c - c).abs() < 0.05, "C = {}", fit.c);
+}
+
+#[test]
+fn resize_fn_adapter_round_trips_through_analyze() {
+    for filter in [
+        KnownFilter::Box,
+        KnownFilter::Triangle,
+        KnownFilter::Mitchell,
+        KnownFilter::Lanczos3,
+    ] {
+        let resize = resamplescope::resize_fn(filter);
+        let config = AnalysisConfig {
+            transfer: Transfer::Linear,
+            detect_edges: false,
+        };
+        let result = resamplescope::analyze(&*resize, &config).unwrap();
+
+        let best = &result.scores[0];
+        assert_eq!(best.filter, filter, "expected {filter}, got {best}");
+        assert!(
+            best.correlation > 0.999,
+            "{filter}: correlation too low: {}",
+            best.correlation
+        );
+    }
+}
+
+#[test]
+fn snapshot_round_trips_through_text() {
+    let config = AnalysisConfig {
+        transfer: Transfer::Linear,
+        detect_edges: true,
+    };
+    let result = resamplescope::analyze(&nn_resize, &config).unwrap();
+
+    let snapshot = result.to_snapshot();
+    let text = snapshot.to_text();
+    let loaded =
+        resamplescope::AnalysisSnapshot::from_text(&text).expect("snapshot should parse");
+
+    let diff = resamplescope::compare_snapshots(&snapshot, &loaded, 1e-9);
+    assert!(
+        diff.within_tolerance,
+        "round-tripped snapshot drifted: {diff:?}"
+    );
+}
+
 #[test]
 fn downscale_only_analysis() {
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result = resamplescope::analyze_downscale(&nn_resize, &config).unwrap();
@@ -1,6 +1,6 @@
 use imgref::{ImgRef, ImgVec};
 use linear_srgb::default::{linear_to_srgb_u8, srgb_u8_to_linear};
-use resamplescope::{AnalysisConfig, KnownFilter};
+use resamplescope::{AnalysisConfig, KnownFilter, Transfer};
 use std::fs;
 use std::path::Path;
 
@@ -85,6 +85,43 @@ fn with_linear_srgb(
     }
 }
 
+/// Wrap a raw-byte resizer with an arbitrary [`Transfer`]:
+/// encoded u8 -> linear u8 -> resize in linear -> encoded u8.
+///
+/// Generalizes [`with_linear_srgb`] to any transfer function, so the same
+/// bilinear/Lanczos pipelines can be probed under gamma, Rec.709, or a
+/// custom LUT instead of only sRGB.
+fn with_transfer(
+    raw_resize: impl Fn(ImgRef<'_, u8>, usize, usize) -> ImgVec<u8>,
+    transfer: Transfer,
+) -> impl Fn(ImgRef<'_, u8>, usize, usize) -> ImgVec<u8> {
+    move |src: ImgRef<'_, u8>, dst_w: usize, dst_h: usize| -> ImgVec<u8> {
+        let src_w = src.width();
+        let src_h = src.height();
+        let lut = transfer.decode_lut();
+
+        let linear_u8: Vec<u8> = (0..src_h)
+            .flat_map(|y| {
+                (0..src_w).map(move |x| {
+                    let raw = src.buf()[y * src.stride() + x];
+                    (lut[raw as usize] * 255.0).round().clamp(0.0, 255.0) as u8
+                })
+            })
+            .collect();
+        let linear_img = ImgVec::new(linear_u8, src_w, src_h);
+
+        let resized_linear = raw_resize(linear_img.as_ref(), dst_w, dst_h);
+
+        let dst: Vec<u8> = resized_linear
+            .buf()
+            .iter()
+            .map(|&v| transfer.encode_u8(v as f64 / 255.0))
+            .collect();
+
+        ImgVec::new(dst, dst_w, dst_h)
+    }
+}
+
 /// Validate that linear-srgb round-trips correctly at key values.
 #[test]
 fn linear_srgb_roundtrip_accuracy() {
@@ -132,7 +169,7 @@ fn linear_srgb_bilinear_identified_as_triangle() {
     // With srgb=true, resamplescope should correct for the nonlinear transfer
     // and correctly identify the underlying triangle filter.
     let config_srgb = AnalysisConfig {
-        srgb: true,
+        transfer: Transfer::Srgb,
         detect_edges: false,
     };
     let result_srgb = resamplescope::analyze(&srgb_bilinear, &config_srgb).unwrap();
@@ -161,7 +198,7 @@ fn linear_srgb_bilinear_identified_as_triangle() {
     // Without srgb correction, the distorted shape should still be *somewhat*
     // identifiable but with lower correlation.
     let config_linear = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
     let result_linear = resamplescope::analyze(&srgb_bilinear, &config_linear).unwrap();
@@ -190,7 +227,7 @@ fn linear_srgb_lanczos3_identification() {
     });
 
     let config = AnalysisConfig {
-        srgb: true,
+        transfer: Transfer::Srgb,
         detect_edges: false,
     };
     let result = resamplescope::analyze(&srgb_lanczos, &config).unwrap();
@@ -226,7 +263,7 @@ fn linear_srgb_all_filters_survey() {
     fs::create_dir_all(out).unwrap();
 
     let config = AnalysisConfig {
-        srgb: true,
+        transfer: Transfer::Srgb,
         detect_edges: false,
     };
 
@@ -330,3 +367,71 @@ fn linear_srgb_ssim_vs_direct() {
         println!("{:<15} {:>8.6} {:>8}", filter.name(), s, note);
     }
 }
+
+/// Extend the sRGB validation to arbitrary encodings: a bilinear resize
+/// wrapped in gamma-2.2, Rec.709, or a custom LUT should each be
+/// identified as Triangle once analyzed through the matching transfer,
+/// just like the sRGB case above.
+#[test]
+fn arbitrary_transfer_bilinear_identified_as_triangle() {
+    let transfers = [
+        ("gamma 2.2", Transfer::Gamma(2.2)),
+        ("rec709", Transfer::Rec709),
+        (
+            "custom lut (gamma 2.2 table)",
+            Transfer::CustomLut(
+                (0..256)
+                    .map(|i| Transfer::Gamma(2.2).decode(i as f64 / 255.0))
+                    .collect(),
+            ),
+        ),
+    ];
+
+    for (name, transfer) in transfers {
+        let wrapped = with_transfer(bilinear_resize_raw, transfer.clone());
+        let config = AnalysisConfig {
+            transfer,
+            detect_edges: false,
+        };
+        let result = resamplescope::analyze(&wrapped, &config).unwrap();
+        let best = &result.scores[0];
+        println!(
+            "{name}: best={} r={:.4}",
+            best.filter.name(),
+            best.correlation
+        );
+        assert_eq!(
+            best.filter,
+            KnownFilter::Triangle,
+            "{name}: expected Triangle, got {} (r={:.4})",
+            best.filter.name(),
+            best.correlation
+        );
+        assert!(
+            best.correlation > 0.99,
+            "{name}: correlation too low: {:.4}",
+            best.correlation
+        );
+    }
+}
+
+/// [`resamplescope::detect_transfer`] should recover the transfer function
+/// a bilinear resize was actually wrapped in, rather than requiring the
+/// caller to already know it's sRGB vs. linear vs. something else.
+#[test]
+fn detect_transfer_recovers_gamma_22() {
+    let wrapped = with_transfer(bilinear_resize_raw, Transfer::Gamma(2.2));
+    let detection = resamplescope::detect_transfer(&wrapped).unwrap();
+
+    println!("candidates:");
+    for (transfer, correlation) in &detection.candidates {
+        println!("  {transfer:?}: r={correlation:.4}");
+    }
+    println!(
+        "best: {:?} r={:.4}",
+        detection.best_transfer, detection.best_score.correlation
+    );
+
+    assert_eq!(detection.best_transfer, Transfer::Gamma(2.2));
+    assert_eq!(detection.best_score.filter, KnownFilter::Triangle);
+}
@@ -1,5 +1,5 @@
 use imgref::{ImgRef, ImgVec};
-use resamplescope::{AnalysisConfig, KnownFilter};
+use resamplescope::{AnalysisConfig, KnownFilter, Transfer};
 use std::fs;
 use std::path::Path;
 use zenimage::graphics::filters::Filter as ZenFilter;
@@ -86,7 +86,7 @@ fn zenimage_filter_identification_linear() {
     fs::create_dir_all(out).unwrap();
 
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
 
@@ -149,7 +149,7 @@ fn zenimage_filter_identification_srgb() {
     fs::create_dir_all(out).unwrap();
 
     let config = AnalysisConfig {
-        srgb: true,
+        transfer: Transfer::Srgb,
         detect_edges: false,
     };
 
@@ -224,7 +224,7 @@ fn zenimage_all_filters_survey() {
     fs::create_dir_all(out).unwrap();
 
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
 
@@ -1,5 +1,5 @@
 use imgref::{ImgRef, ImgVec};
-use resamplescope::{AnalysisConfig, KnownFilter};
+use resamplescope::{AnalysisConfig, KnownFilter, Transfer};
 use std::fs;
 use std::path::Path;
 
@@ -56,7 +56,7 @@ fn write_visual_output() {
     fs::create_dir_all(out).unwrap();
 
     let config = AnalysisConfig {
-        srgb: false,
+        transfer: Transfer::Linear,
         detect_edges: false,
     };
 